@@ -19,8 +19,7 @@
 //! [adafruit FT232H breakout]: https://www.adafruit.com/product/2264
 //! [libftd2xx crate]: https://github.com/newAM/libftd2xx-rs/
 
-use eeprom25aa02e48::Eeprom25aa02e48;
-use embedded_hal::spi::Polarity;
+use eeprom25aa02e48::{Eeprom25aa02e48, SPI_MODE};
 use ftd2xx_embedded_hal::Ft232hHal;
 
 fn main() {
@@ -29,7 +28,7 @@ fn main() {
         .init_default()
         .expect("Failed to initialize MPSSE");
     let mut spi = dev.spi().unwrap();
-    spi.set_clock_polarity(Polarity::IdleLow);
+    spi.set_clock_polarity(SPI_MODE.polarity);
     let cs = dev.ad3();
 
     let mut eeprom = Eeprom25aa02e48::new(spi, cs);
@@ -19,8 +19,7 @@ fn main() {
 
     let mut page: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
     const BYTE_ADDR: u8 = 0x10;
-    const PAGE_ADDR: u8 = BYTE_ADDR / PAGE_SIZE;
-    assert!(BYTE_ADDR % PAGE_ADDR == 0);
+    const _: () = assert!(BYTE_ADDR.is_multiple_of(PAGE_SIZE));
     println!("Reading page");
     eeprom
         .read(BYTE_ADDR, &mut page)
@@ -0,0 +1,436 @@
+//! Asynchronous driver using [`embedded-hal-async`].
+//!
+//! This module is only available with the `async` feature.
+//!
+//! The asynchronous driver mirrors a subset of the blocking API, methods
+//! link to their blocking counterparts for the full documentation.
+//! The method bodies are not generated from a single source, the blocking
+//! driver is built on the [`embedded-hal`] 0.2 bus and chip select pin and
+//! does not share the [`SpiDevice`] transaction model used here.
+//! Argument checks and instruction encoding are shared with the blocking
+//! driver.
+//!
+//! # Example
+//!
+//! With [embassy] the driver can be used from a task, the SPI device and
+//! delay are provided by your HAL crate.
+//!
+//! ```
+//! # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+//! # use embedded_hal_mock_eh1::eh1::spi::{Mock, Transaction};
+//! # let spi = Mock::new(&[
+//! #   Transaction::transaction_start(),
+//! #   Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+//! #   Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
+//! #   Transaction::transaction_end(),
+//! # ]);
+//! # futures::executor::block_on(async {
+//! use eeprom25aa02e48::asynch::Eeprom25aa02e48;
+//!
+//! let mut eeprom = Eeprom25aa02e48::new(spi);
+//! let eui48: [u8; 6] = eeprom.read_eui48().await?;
+//! # assert_eq!(eui48, [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
+//! # eeprom.free().done();
+//! # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+//! # })?;
+//! # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+//! ```
+//!
+//! [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+//! [`embedded-hal-async`]: https://github.com/rust-embedded/embedded-hal/tree/master/embedded-hal-async
+//! [embassy]: https://embassy.dev
+
+use crate::{
+    check_args, instruction, Error, Eui48, Eui64, Identifier, StatusRegister, EUI48_BYTES,
+    EUI48_MEMORY_ADDRESS, EUI64_BYTES, EUI64_MEMORY_ADDRESS, MAX_TRANSFER, PAGE_SIZE,
+    POLL_INTERVAL_US, WRITE_CYCLE_TIME_US,
+};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::spi::{Operation, SpiDevice};
+
+/// SPI mode of the EEPROM.
+///
+/// See [`crate::SPI_MODE`].
+pub const SPI_MODE: embedded_hal_async::spi::Mode = embedded_hal_async::spi::MODE_0;
+
+/// Asynchronous Microchip 25xx SPI EEPROM driver.
+///
+/// This is generic over the EEPROM capacity and page size in bytes, see
+/// [`crate::Eeprom25x`].
+#[derive(Debug)]
+pub struct Eeprom25x<SPI, const CAPACITY: usize, const PAGE: usize, EUI = ()> {
+    /// SPI device.
+    spi: SPI,
+    /// Reject writes to the factory programmed identifier.
+    protect_eui: bool,
+    /// Write cycle time in microseconds.
+    write_cycle_time: u32,
+    /// Factory programmed identifier.
+    eui: PhantomData<EUI>,
+}
+
+impl<SPI: Default, const CAPACITY: usize, const PAGE: usize, EUI> Default
+    for Eeprom25x<SPI, CAPACITY, PAGE, EUI>
+{
+    fn default() -> Self {
+        let () = Self::ADDRESS_FITS;
+        let () = Self::PAGE_DIVIDES;
+        Eeprom25x {
+            spi: SPI::default(),
+            protect_eui: true,
+            write_cycle_time: WRITE_CYCLE_TIME_US,
+            eui: PhantomData,
+        }
+    }
+}
+
+/// Asynchronous Microchip 25AA02E48 driver.
+pub type Eeprom25aa02e48<SPI> = Eeprom25x<SPI, 256, { PAGE_SIZE as usize }, Eui48>;
+
+/// Asynchronous Microchip 25AA02E64 driver.
+pub type Eeprom25aa02e64<SPI> = Eeprom25x<SPI, 256, { PAGE_SIZE as usize }, Eui64>;
+
+impl<SPI, const CAPACITY: usize, const PAGE: usize, EUI> Eeprom25x<SPI, CAPACITY, PAGE, EUI> {
+    /// Compile time check that every address fits in the single address
+    /// byte of the READ and WRITE instructions.
+    const ADDRESS_FITS: () = assert!(
+        CAPACITY <= 256,
+        "capacity exceeds the 8 bit address of the READ and WRITE instructions"
+    );
+
+    /// Compile time check that the memory array is a whole number of pages.
+    const PAGE_DIVIDES: () = assert!(
+        PAGE != 0 && CAPACITY.is_multiple_of(PAGE),
+        "page size does not divide the capacity"
+    );
+}
+
+impl<SPI: SpiDevice, const CAPACITY: usize, const PAGE: usize, EUI: Identifier>
+    Eeprom25x<SPI, CAPACITY, PAGE, EUI>
+{
+    /// Creates a new driver from an SPI device.
+    pub const fn new(spi: SPI) -> Self {
+        let () = Self::ADDRESS_FITS;
+        let () = Self::PAGE_DIVIDES;
+        Eeprom25x {
+            spi,
+            protect_eui: true,
+            write_cycle_time: WRITE_CYCLE_TIME_US,
+            eui: PhantomData,
+        }
+    }
+
+    /// Free the SPI device from the driver.
+    pub fn free(self) -> SPI {
+        self.spi
+    }
+
+    /// Enable or disable the guard against writes to the factory programmed
+    /// identifier.
+    ///
+    /// The guard is enabled by default.
+    ///
+    /// See [`crate::Eeprom25x::protect_eui`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock_eh1::eh1::spi::{Mock, Transaction};
+    /// # let spi = Mock::new(&[
+    /// #   Transaction::transaction_start(),
+    /// #   Transaction::write_vec(vec![instruction::WREN]),
+    /// #   Transaction::transaction_end(),
+    /// #   Transaction::transaction_start(),
+    /// #   Transaction::write_vec(vec![instruction::WRITE, 0xF0]),
+    /// #   Transaction::write_vec(vec![0x00; 16]),
+    /// #   Transaction::transaction_end(),
+    /// # ]);
+    /// # futures::executor::block_on(async {
+    /// use eeprom25aa02e48::{asynch::Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let data: [u8; 16] = [0x00; 16];
+    /// let result = eeprom.write_page(0xF0, &data).await;
+    /// assert!(matches!(result, Err(Error::ProtectedRegion)));
+    ///
+    /// eeprom.protect_eui(false);
+    /// eeprom.write_page(0xF0, &data).await?;
+    /// # eeprom.free().done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// # })?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn protect_eui(&mut self, enabled: bool) {
+        self.protect_eui = enabled;
+    }
+
+    /// Set the write cycle time in microseconds.
+    ///
+    /// The default is [`WRITE_CYCLE_TIME_US`], this is the initial delay of
+    /// [`wait_ready`] and [`wait_ready_timeout`] before the first read of
+    /// the STATUS register.
+    ///
+    /// See [`crate::Eeprom25x::with_write_cycle_time`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock_eh1::eh1::spi::Mock;
+    /// # let spi = Mock::new(&[]);
+    /// use eeprom25aa02e48::{asynch::Eeprom25aa02e48, WRITE_CYCLE_TIME_US};
+    ///
+    /// let eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.write_cycle_time(), WRITE_CYCLE_TIME_US);
+    ///
+    /// let eeprom = eeprom.with_write_cycle_time(6_000);
+    /// assert_eq!(eeprom.write_cycle_time(), 6_000);
+    /// # eeprom.free().done();
+    /// ```
+    ///
+    /// [`WRITE_CYCLE_TIME_US`]: crate::WRITE_CYCLE_TIME_US
+    /// [`wait_ready`]: Self::wait_ready
+    /// [`wait_ready_timeout`]: Self::wait_ready_timeout
+    pub fn with_write_cycle_time(mut self, us: u32) -> Self {
+        self.write_cycle_time = us;
+        self
+    }
+
+    /// Write cycle time in microseconds.
+    ///
+    /// See [`with_write_cycle_time`] for details.
+    ///
+    /// [`with_write_cycle_time`]: Self::with_write_cycle_time
+    pub const fn write_cycle_time(&self) -> u32 {
+        self.write_cycle_time
+    }
+
+    /// Check that a write of `len` bytes at `address` does not overlap the
+    /// factory programmed identifier.
+    fn check_protected(
+        &self,
+        address: u8,
+        len: usize,
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        if self.protect_eui
+            && len != 0
+            && usize::from(address) + len > CAPACITY.saturating_sub(EUI::BYTES)
+        {
+            Err(Error::ProtectedRegion)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Write `header` followed by `data` with the write enable latch set.
+    ///
+    /// The write latch is reset if the write fails.
+    async fn write_latched(
+        &mut self,
+        header: &[u8],
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        self.write_enable().await?;
+        let result = if data.is_empty() {
+            self.spi.write(header).await
+        } else {
+            self.spi
+                .transaction(&mut [Operation::Write(header), Operation::Write(data)])
+                .await
+        };
+        // write latch automatically resets on successful write
+        if result.is_err() {
+            self.write_disable().await?;
+        }
+        result.map_err(Error::Spi)
+    }
+
+    /// Set the write enable latch.
+    ///
+    /// See [`crate::Eeprom25aa02e48::write_enable`].
+    pub async fn write_enable(&mut self) -> Result<(), Error<SPI::Error, Infallible>> {
+        self.spi
+            .write(&[instruction::WREN])
+            .await
+            .map_err(Error::Spi)
+    }
+
+    /// Reset the write enable latch.
+    pub async fn write_disable(&mut self) -> Result<(), Error<SPI::Error, Infallible>> {
+        self.spi
+            .write(&[instruction::WRDI])
+            .await
+            .map_err(Error::Spi)
+    }
+
+    /// Read from the EEPROM.
+    ///
+    /// See [`crate::Eeprom25aa02e48::read`].
+    pub async fn read(
+        &mut self,
+        address: u8,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        check_args(address as usize + buf.len() <= CAPACITY, Error::OutOfBounds)?;
+        let mut address: u8 = address;
+        for chunk in buf.chunks_mut(MAX_TRANSFER) {
+            let cmd: [u8; 2] = [instruction::READ, address];
+            self.spi
+                .transaction(&mut [Operation::Write(&cmd), Operation::Read(chunk)])
+                .await
+                .map_err(Error::Spi)?;
+            address = address.wrapping_add(chunk.len() as u8);
+        }
+        Ok(())
+    }
+
+    /// Writes up to a page of data to the EEPROM.
+    ///
+    /// See [`crate::Eeprom25aa02e48::write_page`].
+    pub async fn write_page(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        check_args(usize::from(address).is_multiple_of(PAGE), Error::Misaligned)?;
+        check_args(
+            data.len() <= PAGE && address as usize + data.len() <= CAPACITY,
+            Error::OutOfBounds,
+        )?;
+        self.check_protected(address, data.len())?;
+        if data.is_empty() {
+            Ok(())
+        } else {
+            let cmd: [u8; 2] = [instruction::WRITE, address];
+            self.write_latched(&cmd, data).await
+        }
+    }
+
+    /// Perform a raw SPI transfer in a single chip select frame.
+    ///
+    /// See [`crate::Eeprom25x::transaction`].
+    pub async fn transaction(
+        &mut self,
+        bytes: &mut [u8],
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        self.spi.transfer_in_place(bytes).await.map_err(Error::Spi)
+    }
+
+    /// Read the STATUS register.
+    pub async fn read_status_register(
+        &mut self,
+    ) -> Result<StatusRegister, Error<SPI::Error, Infallible>> {
+        let mut buf: [u8; 2] = [instruction::RDSR, 0];
+        self.spi
+            .transfer_in_place(&mut buf)
+            .await
+            .map_err(Error::Spi)?;
+        Ok(StatusRegister::from(buf[1]))
+    }
+
+    /// Returns `true` if a write cycle is in progress.
+    #[doc(alias = "is_write_in_progress")]
+    pub async fn busy(&mut self) -> Result<bool, Error<SPI::Error, Infallible>> {
+        Ok(self.read_status_register().await?.write_in_progress())
+    }
+
+    /// Wait for a write cycle to complete.
+    ///
+    /// This awaits a delay of the [`write_cycle_time`], then polls the write
+    /// in progress bit of the STATUS register until it is cleared, awaiting
+    /// the delay between each poll.
+    ///
+    /// [`write_cycle_time`]: Self::write_cycle_time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock_eh1::eh1::{delay::NoopDelay, spi::{Mock, Transaction}};
+    /// # let spi = Mock::new(&[
+    /// #   Transaction::transaction_start(),
+    /// #   Transaction::write_vec(vec![instruction::WREN]),
+    /// #   Transaction::transaction_end(),
+    /// #   Transaction::transaction_start(),
+    /// #   Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+    /// #   Transaction::write_vec(vec![0x12; 16]),
+    /// #   Transaction::transaction_end(),
+    /// #   Transaction::transaction_start(),
+    /// #   Transaction::transfer_in_place(vec![instruction::RDSR, 0x00], vec![0x00, 0x01]),
+    /// #   Transaction::transaction_end(),
+    /// #   Transaction::transaction_start(),
+    /// #   Transaction::transfer_in_place(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// #   Transaction::transaction_end(),
+    /// # ]);
+    /// # let mut delay = NoopDelay::new();
+    /// # futures::executor::block_on(async {
+    /// use eeprom25aa02e48::asynch::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_page(0x10, &[0x12; 16]).await?;
+    /// eeprom.wait_ready(&mut delay).await?;
+    /// # eeprom.free().done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// # })?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub async fn wait_ready<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        delay.delay_us(self.write_cycle_time).await;
+        while self.busy().await? {
+            delay.delay_us(POLL_INTERVAL_US).await;
+        }
+        Ok(())
+    }
+
+    /// Wait for a write cycle to complete, reading the STATUS register at
+    /// most `max_polls` times.
+    ///
+    /// See [`crate::Eeprom25x::wait_ready_timeout`].
+    pub async fn wait_ready_timeout<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        max_polls: u32,
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        delay.delay_us(self.write_cycle_time).await;
+        for _ in 0..max_polls {
+            if !self.busy().await? {
+                return Ok(());
+            }
+            delay.delay_us(POLL_INTERVAL_US).await;
+        }
+        Err(Error::Timeout)
+    }
+}
+
+impl<SPI: SpiDevice> Eeprom25aa02e48<SPI> {
+    /// Read the EUI-48 MAC address from the EEPROM.
+    pub async fn read_eui48(&mut self) -> Result<[u8; EUI48_BYTES], Error<SPI::Error, Infallible>> {
+        let mut eui48: [u8; EUI48_BYTES] = [0; EUI48_BYTES];
+        self.read(EUI48_MEMORY_ADDRESS, &mut eui48).await?;
+        Ok(eui48)
+    }
+
+    /// Read the EUI-48 MAC address from the EEPROM as an [`Eui48`].
+    pub async fn read_eui48_typed(&mut self) -> Result<Eui48, Error<SPI::Error, Infallible>> {
+        self.read_eui48().await.map(Eui48::from)
+    }
+}
+
+impl<SPI: SpiDevice> Eeprom25aa02e64<SPI> {
+    /// Read the EUI-64 address from the EEPROM.
+    pub async fn read_eui64(&mut self) -> Result<[u8; EUI64_BYTES], Error<SPI::Error, Infallible>> {
+        let mut eui64: [u8; EUI64_BYTES] = [0; EUI64_BYTES];
+        self.read(EUI64_MEMORY_ADDRESS, &mut eui64).await?;
+        Ok(eui64)
+    }
+
+    /// Read the EUI-64 address from the EEPROM as an [`Eui64`].
+    pub async fn read_eui64_typed(&mut self) -> Result<Eui64, Error<SPI::Error, Infallible>> {
+        self.read_eui64().await.map(Eui64::from)
+    }
+}
@@ -0,0 +1,46 @@
+//! CRC-32 (IEEE 802.3) and CRC-8 (SMBus) implementations.
+
+/// Reversed polynomial of the CRC-32 (IEEE 802.3).
+const POLY: u32 = 0xEDB8_8320;
+
+/// Initial value of the CRC, before any data is added.
+pub(crate) const INIT: u32 = 0xFFFF_FFFF;
+
+/// Add `data` to a CRC.
+///
+/// Start with [`INIT`], and finish with [`finish`].
+pub(crate) fn update(mut crc: u32, data: &[u8]) -> u32 {
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask: u32 = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (POLY & mask);
+        }
+    }
+    crc
+}
+
+/// Final CRC value.
+pub(crate) const fn finish(crc: u32) -> u32 {
+    !crc
+}
+
+/// Polynomial of the CRC-8 (SMBus).
+const POLY8: u8 = 0x07;
+
+/// CRC-8 (SMBus) of `data`.
+pub(crate) fn crc8(data: &[u8]) -> u8 {
+    update8(0x00, data)
+}
+
+/// Add `data` to a CRC-8, starting with `0x00`.
+pub(crate) fn update8(mut crc: u8, data: &[u8]) -> u8 {
+    for byte in data {
+        crc ^= *byte;
+        for _ in 0..8 {
+            let mask: u8 = (crc >> 7).wrapping_neg();
+            crc = (crc << 1) ^ (POLY8 & mask);
+        }
+    }
+    crc
+}
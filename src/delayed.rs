@@ -0,0 +1,246 @@
+//! Driver with an owned delay.
+
+use crate::{Eeprom25x, Error, Eui48, Identifier, Interface, PAGE_SIZE};
+use core::fmt;
+use core::ops::{Deref, DerefMut, RangeInclusive};
+use embedded_hal::blocking::delay::DelayUs;
+
+/// Driver with an owned delay.
+///
+/// This pairs the driver with a delay, methods that wait for write cycles to
+/// complete use the owned delay instead of taking a delay argument.
+///
+/// All other driver methods are available through [`Deref`].
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+/// #   hal::spi::Transaction::write(vec![0x12, 0x34]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x10]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 2], vec![0x12, 0x34]),
+/// # ]);
+/// # let mut pin_transactions = Vec::new();
+/// # for _ in 0..4 {
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+/// # }
+/// # let pin = hal::pin::Mock::new(&pin_transactions);
+/// # let delay = hal::delay::MockNoop::new();
+/// use eeprom25aa02e48::Eeprom25aa02e48;
+///
+/// let mut eeprom = Eeprom25aa02e48::new_with_delay(spi, pin, delay);
+/// eeprom.write_data(0x10, &[0x12, 0x34])?;
+/// let mut buf: [u8; 2] = [0; 2];
+/// eeprom.read(0x10, &mut buf)?;
+/// # assert_eq!(buf, [0x12, 0x34]);
+/// let (spi, pin, delay) = eeprom.free();
+/// # { let mut spi = spi; spi.done(); }
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+pub struct DelayedEeprom<
+    SPI,
+    CS,
+    D,
+    const CAPACITY: usize = 256,
+    const PAGE: usize = { PAGE_SIZE as usize },
+    EUI = Eui48,
+    WP = (),
+    HOLD = (),
+> {
+    eeprom: Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+    delay: D,
+}
+
+/// Formats the driver configuration, the delay is omitted.
+impl<SPI, CS, D, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD> fmt::Debug
+    for DelayedEeprom<SPI, CS, D, CAPACITY, PAGE, EUI, WP, HOLD>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DelayedEeprom")
+            .field("eeprom", &self.eeprom)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<SPI, CS, D, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    DelayedEeprom<SPI, CS, D, CAPACITY, PAGE, EUI, WP, HOLD>
+{
+    /// Creates a new driver with an owned delay from a driver and a delay.
+    pub const fn new(eeprom: Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>, delay: D) -> Self {
+        DelayedEeprom { eeprom, delay }
+    }
+
+    /// Split the driver and delay.
+    pub fn split_delay(self) -> (Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>, D) {
+        (self.eeprom, self.delay)
+    }
+}
+
+impl<SPI, CS, D, const CAPACITY: usize, const PAGE: usize, EUI>
+    DelayedEeprom<SPI, CS, D, CAPACITY, PAGE, EUI>
+{
+    /// Free the SPI bus, CS pin, and delay from the driver.
+    pub fn free(self) -> (SPI, CS, D) {
+        let (spi, cs) = self.eeprom.free();
+        (spi, cs, self.delay)
+    }
+}
+
+impl<SPI, CS, D, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD> Deref
+    for DelayedEeprom<SPI, CS, D, CAPACITY, PAGE, EUI, WP, HOLD>
+{
+    type Target = Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.eeprom
+    }
+}
+
+impl<SPI, CS, D, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD> DerefMut
+    for DelayedEeprom<SPI, CS, D, CAPACITY, PAGE, EUI, WP, HOLD>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.eeprom
+    }
+}
+
+impl<SPI, CS, D, SpiError, PinError, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    DelayedEeprom<SPI, CS, D, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+    EUI: Identifier,
+    D: DelayUs<u32>,
+{
+    /// Wait for a write cycle to complete.
+    ///
+    /// See [`Eeprom25x::wait_ready`].
+    pub fn wait_ready(&mut self) -> Result<(), Error<SpiError, PinError>> {
+        self.eeprom.wait_ready(&mut self.delay)
+    }
+
+    /// Wait for a write cycle to complete, reading the STATUS register at
+    /// most `max_polls` times.
+    ///
+    /// See [`Eeprom25x::wait_ready_timeout`].
+    pub fn wait_ready_timeout(&mut self, max_polls: u32) -> Result<(), Error<SpiError, PinError>> {
+        self.eeprom.wait_ready_timeout(&mut self.delay, max_polls)
+    }
+
+    /// Write a single byte and wait for the write cycle to complete.
+    ///
+    /// See [`Eeprom25x::write_byte_and_wait`].
+    pub fn write_byte_and_wait(
+        &mut self,
+        address: u8,
+        data: u8,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.eeprom
+            .write_byte_and_wait(address, data, &mut self.delay)
+    }
+
+    /// Write up to a page of data and wait for the write cycle to complete.
+    ///
+    /// See [`Eeprom25x::write_page_and_wait`].
+    pub fn write_page_and_wait(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.eeprom
+            .write_page_and_wait(address, data, &mut self.delay)
+    }
+
+    /// Read a byte, modify it, and write it back.
+    ///
+    /// See [`Eeprom25x::modify_byte`].
+    pub fn modify_byte<F: FnOnce(u8) -> u8>(
+        &mut self,
+        address: u8,
+        f: F,
+    ) -> Result<u8, Error<SpiError, PinError>> {
+        self.eeprom.modify_byte(address, f, &mut self.delay)
+    }
+
+    /// Write data of any length.
+    ///
+    /// See [`Eeprom25x::write_data`].
+    pub fn write_data(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.eeprom.write_data(address, data, &mut self.delay)
+    }
+
+    /// Write whole pages of data.
+    ///
+    /// See [`Eeprom25x::write_pages`].
+    pub fn write_pages(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.eeprom.write_pages(address, data, &mut self.delay)
+    }
+
+    /// Write data of any length and read it back to verify it.
+    ///
+    /// See [`Eeprom25x::write_data_verified`].
+    pub fn write_data_verified(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.eeprom
+            .write_data_verified(address, data, &mut self.delay)
+    }
+
+    /// Write data of any length, skipping pages that already contain the
+    /// data.
+    ///
+    /// See [`Eeprom25x::write_data_if_changed`].
+    pub fn write_data_if_changed(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<usize, Error<SpiError, PinError>> {
+        self.eeprom
+            .write_data_if_changed(address, data, &mut self.delay)
+    }
+
+    /// Erase the memory array.
+    ///
+    /// See [`Eeprom25x::erase`].
+    pub fn erase(&mut self) -> Result<usize, Error<SpiError, PinError>> {
+        self.eeprom.erase(&mut self.delay)
+    }
+
+    /// Write `value` to every address in a range.
+    ///
+    /// See [`Eeprom25x::fill`].
+    pub fn fill(
+        &mut self,
+        value: u8,
+        range: RangeInclusive<u8>,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.eeprom.fill(value, range, &mut self.delay)
+    }
+
+    /// Copy `len` bytes from `src` to `dst`.
+    ///
+    /// See [`Eeprom25x::copy_within`].
+    pub fn copy_within(
+        &mut self,
+        src: u8,
+        dst: u8,
+        len: usize,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.eeprom.copy_within(src, dst, len, &mut self.delay)
+    }
+}
@@ -0,0 +1,206 @@
+//! Power loss safe double buffered storage.
+//!
+//! This module is only available with the `double-buffered` feature.
+
+use crate::{crc, Eeprom25x, Error, Identifier, Interface, PAGE_SIZE};
+use embedded_hal::blocking::delay::DelayUs;
+
+/// Size of the slot trailer in bytes, a sequence number followed by a CRC-32.
+const TRAILER_SIZE: usize = 8;
+
+/// Sequence number of an erased slot.
+const EMPTY: u32 = u32::MAX;
+
+/// `LEN` bytes of data stored in two alternating slots.
+///
+/// Each slot holds the data followed by a sequence number and a CRC-32 of the
+/// data and sequence number.
+/// [`store`](Self::store) writes to the inactive slot, and writes the sequence
+/// number and CRC-32 last, after the data write cycles have completed.
+/// If power is lost during a store the previous copy is still valid, the slot
+/// with the highest sequence number and a valid CRC-32 is the current value.
+///
+/// Each slot starts on a page boundary, so a store never touches a page of
+/// the active slot.
+/// The slots occupy [`pages`](Self::pages) pages starting from the first
+/// page, the pages must be erased (`0xFF`) before first use.
+///
+/// `PAGE` is the page size of the EEPROM in bytes, the default is the 16
+/// byte page of the 25AA02E48 and 25AA02E64.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 4], vec![0x01, 0x02, 0x03, 0x04]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x04]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 8], vec![1, 0, 0, 0, 213, 125, 191, 245]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x10]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 4], vec![0xFF; 4]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x14]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 8], vec![0xFF; 8]),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+/// #   hal::spi::Transaction::write(vec![0x05, 0x06, 0x07, 0x08]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x14]),
+/// #   hal::spi::Transaction::write(vec![2, 0, 0, 0, 197, 87, 110, 201]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// # ]);
+/// # let mut pin_transactions = Vec::new();
+/// # for _ in 0..10 {
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+/// # }
+/// # let pin = hal::pin::Mock::new(&pin_transactions);
+/// # let mut delay = hal::delay::MockNoop::new();
+/// use eeprom25aa02e48::{double_buffered::DoubleBuffered, Eeprom25aa02e48};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+/// // slots in page 0 and page 1
+/// let mut config: DoubleBuffered<4> = DoubleBuffered::new(0);
+/// assert_eq!(config.pages(), 2);
+///
+/// assert_eq!(config.load(&mut eeprom)?, Some([0x01, 0x02, 0x03, 0x04]));
+/// config.store(&mut eeprom, &[0x05, 0x06, 0x07, 0x08], &mut delay)?;
+/// # eeprom.free().0.done();
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoubleBuffered<const LEN: usize, const PAGE: usize = { PAGE_SIZE as usize }> {
+    /// Address of the first slot.
+    start: u16,
+    /// Index of the active slot, `None` if neither slot is valid.
+    slot: Option<u8>,
+    /// Sequence number of the active slot.
+    seq: u32,
+}
+
+impl<const LEN: usize, const PAGE: usize> DoubleBuffered<LEN, PAGE> {
+    /// Size of a slot in bytes, rounded up to a whole number of pages.
+    const SLOT_SIZE: usize = (LEN + TRAILER_SIZE).div_ceil(PAGE) * PAGE;
+
+    /// Creates new double buffered storage starting from a page index.
+    ///
+    /// For example `2` is the page from `0x20` to `0x2F`.
+    ///
+    /// Neither slot is active until [`load`](Self::load) is called.
+    pub const fn new(page: u8) -> Self {
+        DoubleBuffered {
+            start: page as u16 * PAGE as u16,
+            slot: None,
+            seq: 0,
+        }
+    }
+
+    /// Number of pages used by both slots.
+    pub const fn pages(&self) -> usize {
+        2 * Self::SLOT_SIZE / PAGE
+    }
+
+    /// Address of a slot.
+    fn address(&self, slot: u8) -> u8 {
+        (usize::from(self.start) + usize::from(slot) * Self::SLOT_SIZE) as u8
+    }
+
+    /// Returns `true` if both slots are within the capacity.
+    fn in_bounds(&self, capacity: usize) -> bool {
+        usize::from(self.start) + 2 * Self::SLOT_SIZE <= capacity
+    }
+
+    /// CRC-32 of the data and sequence number.
+    fn crc(data: &[u8; LEN], seq: u32) -> u32 {
+        crc::finish(crc::update(
+            crc::update(crc::INIT, data),
+            &seq.to_le_bytes(),
+        ))
+    }
+
+    /// Load the newest valid copy from the EEPROM.
+    ///
+    /// Returns `None` if neither slot is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the slots exceed the EEPROM capacity.
+    pub fn load<SPI, CS, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &mut self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+    ) -> Result<Option<[u8; LEN]>, Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+    {
+        self.slot = None;
+        self.seq = 0;
+
+        if !self.in_bounds(CAPACITY) {
+            return Err(Error::OutOfBounds);
+        }
+
+        let mut newest: Option<[u8; LEN]> = None;
+        for slot in 0..2 {
+            let address: u8 = self.address(slot);
+            let mut data: [u8; LEN] = [0; LEN];
+            eeprom.read(address, &mut data)?;
+            let trailer: [u8; TRAILER_SIZE] = eeprom.read_array(address + LEN as u8)?;
+            let seq: u32 = u32::from_le_bytes([trailer[0], trailer[1], trailer[2], trailer[3]]);
+            let crc: u32 = u32::from_le_bytes([trailer[4], trailer[5], trailer[6], trailer[7]]);
+            if seq != EMPTY
+                && crc == Self::crc(&data, seq)
+                && (self.slot.is_none() || seq > self.seq)
+            {
+                self.slot = Some(slot);
+                self.seq = seq;
+                newest = Some(data);
+            }
+        }
+        Ok(newest)
+    }
+
+    /// Store a new copy in the inactive slot, waiting for the write cycles to
+    /// complete.
+    ///
+    /// Call [`load`](Self::load) first, otherwise the active slot is unknown
+    /// and the store may overwrite the newest copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the slots exceed the EEPROM capacity.
+    pub fn store<SPI, CS, D, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &mut self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+        data: &[u8; LEN],
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+        D: DelayUs<u32>,
+    {
+        if !self.in_bounds(CAPACITY) {
+            return Err(Error::OutOfBounds);
+        }
+
+        let (slot, seq) = match self.slot {
+            Some(slot) => (slot ^ 1, self.seq.wrapping_add(1)),
+            None => (0, 0),
+        };
+
+        let mut trailer: [u8; TRAILER_SIZE] = [0; TRAILER_SIZE];
+        trailer[..4].copy_from_slice(&seq.to_le_bytes());
+        trailer[4..].copy_from_slice(&Self::crc(data, seq).to_le_bytes());
+
+        let address: u8 = self.address(slot);
+        eeprom.write_data(address, data, delay)?;
+        eeprom.write_data(address + LEN as u8, &trailer, delay)?;
+
+        self.slot = Some(slot);
+        self.seq = seq;
+        Ok(())
+    }
+}
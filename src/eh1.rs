@@ -0,0 +1,146 @@
+//! [`embedded-hal`] 1.0 support.
+//!
+//! This module is only available with the `eh1` feature.
+//!
+//! [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+
+use crate::{
+    private, CsPolarity, Eeprom25x, Error, Interface, WriteInterface, WRITE_CYCLE_TIME_US,
+};
+use ::eh1::spi::{Operation, SpiDevice};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+/// SPI mode of the EEPROM.
+///
+/// See [`crate::SPI_MODE`].
+pub const SPI_MODE: ::eh1::spi::Mode = ::eh1::spi::MODE_0;
+
+/// Chip select placeholder for drivers created from a [`SpiDevice`].
+///
+/// The [`SpiDevice`] manages chip select.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceCs;
+
+impl<SPI: SpiDevice> private::Sealed<DeviceCs> for SPI {}
+
+impl<SPI: SpiDevice> WriteInterface<DeviceCs> for SPI {
+    type SpiError = SPI::Error;
+    type PinError = Infallible;
+
+    fn write_frame(
+        &mut self,
+        _cs: &mut DeviceCs,
+        _polarity: CsPolarity,
+        header: &[u8],
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        if data.is_empty() {
+            self.write(header)
+        } else {
+            self.transaction(&mut [Operation::Write(header), Operation::Write(data)])
+        }
+        .map_err(Error::Spi)
+    }
+}
+
+impl<SPI: SpiDevice> Interface<DeviceCs> for SPI {
+    fn read_frame(
+        &mut self,
+        _cs: &mut DeviceCs,
+        _polarity: CsPolarity,
+        header: &[u8],
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        self.transaction(&mut [Operation::Write(header), Operation::Read(buf)])
+            .map_err(Error::Spi)
+    }
+
+    fn transfer_frame(
+        &mut self,
+        _cs: &mut DeviceCs,
+        _polarity: CsPolarity,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error, Infallible>> {
+        self.transfer_in_place(buf).map_err(Error::Spi)
+    }
+}
+
+impl<SPI: SpiDevice, const CAPACITY: usize, const PAGE: usize, EUI>
+    Eeprom25x<SPI, DeviceCs, CAPACITY, PAGE, EUI>
+{
+    /// Creates a new driver from an [`embedded-hal`] 1.0 SPI device.
+    ///
+    /// The SPI device manages chip select, which allows the bus to be shared
+    /// with other devices, for example with [`embedded-hal-bus`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock_eh1::eh1::spi::{Mock, Transaction};
+    /// # let spi = Mock::new(&[
+    /// #   Transaction::transaction_start(),
+    /// #   Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
+    /// #   Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new_from_device(spi);
+    /// let eui48: [u8; 6] = eeprom.read_eui48()?;
+    /// # assert_eq!(eui48, [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+    /// [`embedded-hal-bus`]: https://github.com/rust-embedded/embedded-hal/tree/master/embedded-hal-bus
+    pub const fn new_from_device(spi: SPI) -> Self {
+        let () = Self::ADDRESS_FITS;
+        let () = Self::PAGE_DIVIDES;
+        Eeprom25x {
+            spi,
+            cs: DeviceCs,
+            wp: (),
+            hold: (),
+            cs_polarity: CsPolarity::ActiveLow,
+            protect_eui: true,
+            write_cycle_time: WRITE_CYCLE_TIME_US,
+            verify_write_enable: None,
+            eui: PhantomData,
+        }
+    }
+}
+
+/// Adapter to use an [`embedded-hal`] 1.0 delay with methods that wait for
+/// write cycles, such as [`Eeprom25x::wait_ready`].
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock_eh1::eh1::{delay::NoopDelay, spi::{Mock, Transaction}};
+/// # let spi = Mock::new(&[
+/// #   Transaction::transaction_start(),
+/// #   Transaction::transfer_in_place(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// #   Transaction::transaction_end(),
+/// # ]);
+/// # let delay = NoopDelay::new();
+/// use eeprom25aa02e48::{eh1::Delay, Eeprom25aa02e48};
+///
+/// let mut eeprom = Eeprom25aa02e48::new_from_device(spi);
+/// eeprom.wait_ready(&mut Delay(delay))?;
+/// # eeprom.free().0.done();
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+///
+/// [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Delay<D>(pub D);
+
+impl<D: ::eh1::delay::DelayNs> embedded_hal::blocking::delay::DelayUs<u32> for Delay<D> {
+    fn delay_us(&mut self, us: u32) {
+        self.0.delay_us(us)
+    }
+}
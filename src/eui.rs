@@ -0,0 +1,506 @@
+//! Extended unique identifiers.
+
+use crate::{private, EUI48_BYTES, EUI64_BYTES, MICROCHIP_OUI};
+use core::fmt;
+use core::str::FromStr;
+
+/// Factory programmed identifier of an EEPROM.
+///
+/// This is the `EUI` parameter of [`Eeprom25x`], [`Eui48`] for the
+/// 25AA02E48, [`Eui64`] for the 25AA02E64, and `()` for EEPROMs without an
+/// identifier.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::{Eui48, Eui64, Identifier};
+///
+/// assert_eq!(<Eui48 as Identifier>::BYTES, 6);
+/// assert_eq!(<Eui64 as Identifier>::BYTES, 8);
+/// assert_eq!(<() as Identifier>::BYTES, 0);
+/// ```
+///
+/// [`Eeprom25x`]: crate::Eeprom25x
+pub trait Identifier: private::SealedIdentifier {
+    /// Number of bytes at the end of the memory array occupied by the
+    /// identifier.
+    const BYTES: usize;
+}
+
+impl private::SealedIdentifier for () {}
+impl Identifier for () {
+    const BYTES: usize = 0;
+}
+
+impl private::SealedIdentifier for Eui48 {}
+impl Identifier for Eui48 {
+    const BYTES: usize = EUI48_BYTES;
+}
+
+impl private::SealedIdentifier for Eui64 {}
+impl Identifier for Eui64 {
+    const BYTES: usize = EUI64_BYTES;
+}
+
+/// Returns `true` if `mac` is all `0x00` or all `0xFF`.
+///
+/// These values are typically read from a disconnected or erased EEPROM.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::is_blank;
+///
+/// assert!(is_blank(&[0x00; 6]));
+/// assert!(is_blank(&[0xFF; 6]));
+/// assert!(!is_blank(&[0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]));
+/// ```
+pub const fn is_blank(mac: &[u8; EUI48_BYTES]) -> bool {
+    let mut all_zero: bool = true;
+    let mut all_ones: bool = true;
+    let mut i: usize = 0;
+    while i < EUI48_BYTES {
+        all_zero &= mac[i] == 0x00;
+        all_ones &= mac[i] == 0xFF;
+        i += 1;
+    }
+    all_zero || all_ones
+}
+
+/// Returns `true` if `mac` starts with the [`MICROCHIP_OUI`] and is not
+/// [blank](is_blank).
+///
+/// This is the check performed by [`probe`], for a MAC address from any
+/// source.
+///
+/// [`probe`]: crate::Eeprom25aa02e48::probe
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::validate_eui48;
+///
+/// assert!(validate_eui48(&[0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]));
+/// assert!(!validate_eui48(&[0x02, 0x00, 0x00, 0x12, 0x34, 0x56]));
+/// assert!(!validate_eui48(&[0xFF; 6]));
+/// ```
+pub const fn validate_eui48(mac: &[u8; EUI48_BYTES]) -> bool {
+    mac[0] == MICROCHIP_OUI[0]
+        && mac[1] == MICROCHIP_OUI[1]
+        && mac[2] == MICROCHIP_OUI[2]
+        && !is_blank(mac)
+}
+
+/// EUI-48 MAC address.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::Eui48;
+///
+/// let eui48 = Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+/// assert_eq!(eui48.octets(), [0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+/// assert_eq!(format!("{}", eui48), "00:04:A3:12:34:56");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Eui48([u8; EUI48_BYTES]);
+
+impl Eui48 {
+    /// Octets of the EUI-48.
+    pub const fn octets(&self) -> [u8; EUI48_BYTES] {
+        self.0
+    }
+
+    /// Reference to the octets of the EUI-48.
+    pub const fn as_bytes(&self) -> &[u8; EUI48_BYTES] {
+        &self.0
+    }
+
+    /// Organizationally unique identifier, the first three octets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::{Eui48, MICROCHIP_OUI};
+    ///
+    /// let eui48 = Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// assert_eq!(eui48.oui(), MICROCHIP_OUI);
+    /// ```
+    pub const fn oui(&self) -> [u8; 3] {
+        [self.0[0], self.0[1], self.0[2]]
+    }
+
+    /// Returns `true` if this is a multicast address.
+    ///
+    /// This is the I/G bit, the least significant bit of the first octet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// assert!(!Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]).is_multicast());
+    /// assert!(Eui48::from([0x01, 0x00, 0x5E, 0x00, 0x00, 0x01]).is_multicast());
+    /// ```
+    pub const fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    /// Returns `true` if this is a unicast address.
+    pub const fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+
+    /// Returns `true` if this is a locally administered address.
+    ///
+    /// This is the U/L bit, the second least significant bit of the first
+    /// octet.
+    /// Factory programmed addresses are universally administered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// assert!(!Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]).is_local());
+    /// assert!(Eui48::from([0x02, 0x00, 0x00, 0x12, 0x34, 0x56]).is_local());
+    /// ```
+    pub const fn is_local(&self) -> bool {
+        self.0[0] & 0x02 != 0
+    }
+
+    /// Locally administered unicast address derived from this address and
+    /// `index`.
+    ///
+    /// This is intended for virtual interfaces that need additional addresses
+    /// derived from the factory programmed address.
+    ///
+    /// The lower 6 bits of `index` replace the upper 6 bits of the first octet,
+    /// and the upper 2 bits of `index` are mixed into the last octet.
+    /// Derived addresses are distinct for every `index`, and never equal to a
+    /// universally administered address.
+    /// For an `index` below 64 the derived addresses are also distinct from
+    /// the derived addresses of other EUI-48s with the same first octet, such
+    /// as all EUI-48s with the Microchip OUI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// let eui48 = Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// let derived = eui48.derived_local(1);
+    /// assert_eq!(derived.octets(), [0x06, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// assert!(derived.is_local());
+    /// assert!(derived.is_unicast());
+    ///
+    /// let mut addresses: Vec<Eui48> = (0..=u8::MAX).map(|i| eui48.derived_local(i)).collect();
+    /// addresses.sort();
+    /// addresses.dedup();
+    /// assert_eq!(addresses.len(), 256);
+    /// ```
+    pub const fn derived_local(&self, index: u8) -> Eui48 {
+        let mut octets: [u8; EUI48_BYTES] = self.0;
+        // U/L bit set, I/G bit clear
+        octets[0] = (index << 2) | 0x02;
+        octets[5] ^= index >> 6;
+        Eui48(octets)
+    }
+
+    /// Modified EUI-64 interface identifier for IPv6.
+    ///
+    /// This is the EUI-48 with `0xFF 0xFE` inserted after the OUI and the
+    /// U/L bit inverted, from appendix A of [RFC 4291].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// let eui48 = Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// assert_eq!(
+    ///     eui48.to_modified_eui64(),
+    ///     [0x02, 0x04, 0xA3, 0xFF, 0xFE, 0x12, 0x34, 0x56]
+    /// );
+    /// ```
+    ///
+    /// [RFC 4291]: https://www.rfc-editor.org/rfc/rfc4291#appendix-A
+    pub const fn to_modified_eui64(&self) -> [u8; EUI64_BYTES] {
+        [
+            self.0[0] ^ 0x02,
+            self.0[1],
+            self.0[2],
+            0xFF,
+            0xFE,
+            self.0[3],
+            self.0[4],
+            self.0[5],
+        ]
+    }
+
+    /// IPv6 link-local address (`fe80::/64`) with the interface identifier
+    /// from [`to_modified_eui64`].
+    ///
+    /// This method is only available with the `smoltcp` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    /// use smoltcp::wire::Ipv6Address;
+    ///
+    /// let eui48 = Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// assert_eq!(
+    ///     eui48.to_link_local_ipv6(),
+    ///     Ipv6Address::new(0xFE80, 0, 0, 0, 0x0204, 0xA3FF, 0xFE12, 0x3456)
+    /// );
+    /// ```
+    ///
+    /// [`to_modified_eui64`]: Self::to_modified_eui64
+    #[cfg(feature = "smoltcp")]
+    pub fn to_link_local_ipv6(&self) -> smoltcp::wire::Ipv6Address {
+        let mut octets: [u8; 16] = [0; 16];
+        octets[0] = 0xFE;
+        octets[1] = 0x80;
+        octets[8..].copy_from_slice(&self.to_modified_eui64());
+        smoltcp::wire::Ipv6Address::from(octets)
+    }
+}
+
+impl From<[u8; EUI48_BYTES]> for Eui48 {
+    fn from(octets: [u8; EUI48_BYTES]) -> Self {
+        Eui48(octets)
+    }
+}
+
+impl From<Eui48> for [u8; EUI48_BYTES] {
+    fn from(eui48: Eui48) -> Self {
+        eui48.0
+    }
+}
+
+impl fmt::Display for Eui48 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
+            self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5]
+        )
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Eui48 {
+    /// Format as uppercase colon separated hexadecimal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// struct Writer(String);
+    ///
+    /// impl ufmt::uWrite for Writer {
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+    ///         self.0.push_str(s);
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let eui48 = Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// let mut w = Writer(String::new());
+    /// ufmt::uwrite!(w, "{}", eui48)?;
+    /// assert_eq!(w.0, "00:04:A3:12:34:56");
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        for (idx, octet) in self.0.iter().enumerate() {
+            if idx != 0 {
+                f.write_char(':')?;
+            }
+            f.write_char(char::from(HEX[usize::from(octet >> 4)]))?;
+            f.write_char(char::from(HEX[usize::from(octet & 0xF)]))?;
+        }
+        Ok(())
+    }
+}
+
+/// Error parsing an [`Eui48`] from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParseEuiError {
+    /// String is not 17 characters long.
+    Length,
+    /// Octets are not separated by `:` or `-`, or separators are mixed.
+    Separator,
+    /// Octet is not a hexadecimal number.
+    Digit,
+}
+
+impl fmt::Display for ParseEuiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseEuiError::Length => f.write_str("invalid EUI-48 length, expected 17 characters"),
+            ParseEuiError::Separator => {
+                f.write_str("invalid EUI-48 separator, expected ':' or '-'")
+            }
+            ParseEuiError::Digit => f.write_str("invalid EUI-48 octet, expected hexadecimal"),
+        }
+    }
+}
+
+impl core::error::Error for ParseEuiError {}
+
+impl FromStr for Eui48 {
+    type Err = ParseEuiError;
+
+    /// Parse an EUI-48 from octets separated by `:` or `-`.
+    ///
+    /// Hexadecimal digits are case-insensitive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::{eui::ParseEuiError, Eui48};
+    ///
+    /// let eui48: Eui48 = "00:04:a3:12:34:56".parse()?;
+    /// assert_eq!(eui48.octets(), [0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// assert_eq!("00-04-A3-12-34-56".parse(), Ok(eui48));
+    /// assert_eq!(eui48.to_string().parse(), Ok(eui48));
+    /// assert_eq!("00:04:A3:12:34".parse::<Eui48>(), Err(ParseEuiError::Length));
+    /// # Ok::<(), ParseEuiError>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes: &[u8] = s.as_bytes();
+        if bytes.len() != EUI48_BYTES * 3 - 1 {
+            return Err(ParseEuiError::Length);
+        }
+
+        let separator: u8 = bytes[2];
+        if separator != b':' && separator != b'-' {
+            return Err(ParseEuiError::Separator);
+        }
+
+        let mut octets: [u8; EUI48_BYTES] = [0; EUI48_BYTES];
+        for (idx, octet) in octets.iter_mut().enumerate() {
+            let start: usize = idx * 3;
+            if idx != 0 && bytes[start - 1] != separator {
+                return Err(ParseEuiError::Separator);
+            }
+            let digits: &str = s.get(start..start + 2).ok_or(ParseEuiError::Digit)?;
+            if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(ParseEuiError::Digit);
+            }
+            *octet = u8::from_str_radix(digits, 16).map_err(|_| ParseEuiError::Digit)?;
+        }
+        Ok(Eui48(octets))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Eui48 {
+    /// Serialize as a colon separated string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// let eui48 = Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// let json: String = serde_json::to_string(&eui48)?;
+    /// assert_eq!(json, r#""00:04:A3:12:34:56""#);
+    /// assert_eq!(serde_json::from_str::<Eui48>(&json)?, eui48);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Eui48 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Eui48;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an EUI-48 string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Eui48, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(feature = "smoltcp")]
+impl From<Eui48> for smoltcp::wire::EthernetAddress {
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    /// use smoltcp::wire::EthernetAddress;
+    ///
+    /// let eui48 = Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// let mac: EthernetAddress = eui48.into();
+    /// assert_eq!(mac.as_bytes(), &[0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// ```
+    fn from(eui48: Eui48) -> Self {
+        smoltcp::wire::EthernetAddress(eui48.0)
+    }
+}
+
+/// EUI-64 address.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::Eui64;
+///
+/// let eui64 = Eui64::from([0x00, 0x04, 0xA3, 0xFF, 0xFE, 0x12, 0x34, 0x56]);
+/// assert_eq!(format!("{}", eui64), "00:04:A3:FF:FE:12:34:56");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Eui64([u8; EUI64_BYTES]);
+
+impl Eui64 {
+    /// Octets of the EUI-64.
+    pub const fn octets(&self) -> [u8; EUI64_BYTES] {
+        self.0
+    }
+}
+
+impl From<[u8; EUI64_BYTES]> for Eui64 {
+    fn from(octets: [u8; EUI64_BYTES]) -> Self {
+        Eui64(octets)
+    }
+}
+
+impl From<Eui64> for [u8; EUI64_BYTES] {
+    fn from(eui64: Eui64) -> Self {
+        eui64.0
+    }
+}
+
+impl fmt::Display for Eui64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
+            self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5], self.0[6], self.0[7]
+        )
+    }
+}
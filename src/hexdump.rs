@@ -0,0 +1,68 @@
+//! Memory image formatting.
+
+use core::fmt;
+
+/// Number of bytes on each line of a [`HexDump`].
+const LINE_LEN: usize = 16;
+
+/// Hex dump of a memory image.
+///
+/// Each line has the offset of the first byte, 16 bytes in hexadecimal, and
+/// the printable ASCII characters of the bytes, with `.` for other bytes.
+///
+/// This formats directly to the writer, no allocation is required.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::HexDump;
+///
+/// let image: [u8; 20] = *b"Hello, world!\x00\x01\x02\xFF\xFF\xFF\xFF";
+/// assert_eq!(
+///     format!("{}", HexDump(&image)),
+///     "00: 48 65 6C 6C 6F 2C 20 77 6F 72 6C 64 21 00 01 02  Hello, world!...\n\
+///      10: FF FF FF FF                                      ....\n"
+/// );
+/// ```
+///
+/// With a memory image from [`read_all`]:
+///
+/// ```no_run
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[]);
+/// # let pin = hal::pin::Mock::new(&[]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, HexDump};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+/// println!("{}", HexDump(&eeprom.read_all()?));
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+///
+/// [`read_all`]: crate::Eeprom25x::read_all
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexDump<'a>(pub &'a [u8]);
+
+impl fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (line, chunk) in self.0.chunks(LINE_LEN).enumerate() {
+            write!(f, "{:02X}:", line * LINE_LEN)?;
+            for byte in chunk {
+                write!(f, " {:02X}", byte)?;
+            }
+            for _ in chunk.len()..LINE_LEN {
+                f.write_str("   ")?;
+            }
+            f.write_str("  ")?;
+            for &byte in chunk {
+                let c: char = if byte.is_ascii_graphic() || byte == b' ' {
+                    byte.into()
+                } else {
+                    '.'
+                };
+                write!(f, "{}", c)?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
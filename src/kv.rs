@@ -0,0 +1,292 @@
+//! Key-value record store.
+//!
+//! This module is only available with the `kv` feature.
+
+use crate::{crc, Eeprom25x, Error, Identifier, Interface, PAGE_SIZE};
+use core::ops::RangeInclusive;
+use embedded_hal::blocking::delay::DelayUs;
+
+/// Size of the slot header in bytes, the key, length, and CRC-8.
+const HEADER_SIZE: usize = 3;
+
+/// Key of an empty slot.
+pub const EMPTY: u8 = 0xFF;
+
+/// Result of a search for a key.
+enum Slot {
+    /// Slot index and header of the record.
+    Found(u16, [u8; HEADER_SIZE]),
+    /// The key was not found, index of the first empty slot.
+    Empty(u16),
+    /// The key was not found, and there are no empty slots.
+    Full,
+}
+
+/// Fixed slot key-value store spread across a range of EEPROM pages.
+///
+/// Each slot holds a 1 byte key, the 1 byte length of the value, a CRC-8 of
+/// the key, length, and value, followed by up to `N` bytes of value.
+/// Slots are `N + 3` bytes and are packed back to back, slots may cross page
+/// boundaries.
+///
+/// The 256 byte array limits the number of records, with all 16 pages in use
+/// there are `256 / (N + 3)` slots, for example 64 slots of 1 byte values or
+/// 13 slots of 16 byte values.
+/// On the 25AA02E48 and 25AA02E64 the last page holds the factory programmed
+/// identifier and should not be part of the range, which leaves
+/// `240 / (N + 3)` slots.
+///
+/// `PAGE` is the page size of the EEPROM in bytes, the default is the 16
+/// byte page of the 25AA02E48 and 25AA02E64.
+///
+/// The key [`EMPTY`] marks an empty slot and cannot be used as a key.
+/// The pages must be erased (`0xFF`) before first use.
+///
+/// Setting a value writes the value first and the header last.
+/// If the write is interrupted the CRC-8 no longer matches and the record is
+/// lost, [`get`](Self::get) skips records with an invalid CRC-8.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 3], vec![0xFF; 3]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x07]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 3], vec![0xFF; 3]),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x03]),
+/// #   hal::spi::Transaction::write(vec![0x12, 0x34, 0x56]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x00]),
+/// #   hal::spi::Transaction::write(vec![0x01, 0x03, 0x24]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 3], vec![0x01, 0x03, 0x24]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x03]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 3], vec![0x12, 0x34, 0x56]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 3], vec![0x01, 0x03, 0x24]),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x00]),
+/// #   hal::spi::Transaction::write(vec![0xFF; 3]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// # ]);
+/// # let mut pin_transactions = Vec::new();
+/// # for _ in 0..14 {
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+/// # }
+/// # let pin = hal::pin::Mock::new(&pin_transactions);
+/// # let mut delay = hal::delay::MockNoop::new();
+/// use eeprom25aa02e48::{kv::KvStore, Eeprom25aa02e48};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+/// // 2 slots of up to 4 bytes in page 0
+/// let store: KvStore<4> = KvStore::new(0..=0);
+/// assert_eq!(store.slots(), 2);
+///
+/// store.set(&mut eeprom, 0x01, &[0x12, 0x34, 0x56], &mut delay)?;
+///
+/// let mut buf: [u8; 4] = [0; 4];
+/// assert_eq!(store.get(&mut eeprom, 0x01, &mut buf)?, Some(3));
+/// assert_eq!(buf[..3], [0x12, 0x34, 0x56]);
+///
+/// assert!(store.remove(&mut eeprom, 0x01, &mut delay)?);
+/// # eeprom.free().0.done();
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KvStore<const N: usize, const PAGE: usize = { PAGE_SIZE as usize }> {
+    /// Address of the first slot.
+    start: u16,
+    /// Number of slots.
+    slots: u16,
+}
+
+impl<const N: usize, const PAGE: usize> KvStore<N, PAGE> {
+    /// Size of a slot in bytes.
+    const SLOT_SIZE: usize = HEADER_SIZE + N;
+
+    /// Creates a new store in a range of page indices.
+    ///
+    /// For example `0..=3` is the 4 pages from `0x00` to `0x3F`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::kv::KvStore;
+    ///
+    /// // two 16 byte pages
+    /// assert_eq!(KvStore::<4>::new(0..=1).slots(), 4);
+    /// // two 8 byte pages
+    /// assert_eq!(KvStore::<4, 8>::new(0..=1).slots(), 2);
+    /// ```
+    pub const fn new(pages: RangeInclusive<u8>) -> Self {
+        let first: u8 = *pages.start();
+        let last: u8 = *pages.end();
+        let bytes: usize = if last < first || N > u8::MAX as usize {
+            0
+        } else {
+            (last - first) as usize * PAGE + PAGE
+        };
+        KvStore {
+            start: first as u16 * PAGE as u16,
+            slots: (bytes / Self::SLOT_SIZE) as u16,
+        }
+    }
+
+    /// Number of slots, the maximum number of records.
+    pub const fn slots(&self) -> usize {
+        self.slots as usize
+    }
+
+    /// Address of a slot.
+    fn address(&self, slot: u16) -> u8 {
+        (usize::from(self.start) + usize::from(slot) * Self::SLOT_SIZE) as u8
+    }
+
+    /// Returns `true` if all slots are within the capacity.
+    fn in_bounds(&self, capacity: usize) -> bool {
+        usize::from(self.start) + usize::from(self.slots) * Self::SLOT_SIZE <= capacity
+    }
+
+    /// Find the slot holding `key`, or the first empty slot.
+    fn find<SPI, CS, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+        key: u8,
+    ) -> Result<Slot, Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+    {
+        if !self.in_bounds(CAPACITY) {
+            return Err(Error::OutOfBounds);
+        }
+
+        let mut empty: Option<u16> = None;
+        for slot in 0..self.slots {
+            let header: [u8; HEADER_SIZE] = eeprom.read_array(self.address(slot))?;
+            if header[0] == key {
+                return Ok(Slot::Found(slot, header));
+            } else if header[0] == EMPTY && empty.is_none() {
+                empty = Some(slot);
+            }
+        }
+        Ok(empty.map_or(Slot::Full, Slot::Empty))
+    }
+
+    /// Read the value of `key` into `buf`, returning the length of the value.
+    ///
+    /// Returns `None` if there is no valid record for `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `buf` is too small for the value, or
+    /// if the pages exceed the EEPROM capacity.
+    pub fn get<SPI, CS, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+        key: u8,
+        buf: &mut [u8],
+    ) -> Result<Option<usize>, Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+    {
+        if key == EMPTY {
+            return Ok(None);
+        }
+        let (slot, header) = match self.find(eeprom, key)? {
+            Slot::Found(slot, header) => (slot, header),
+            Slot::Empty(_) | Slot::Full => return Ok(None),
+        };
+
+        let address: u8 = self.address(slot);
+        let len: usize = usize::from(header[1]);
+        if len > N {
+            return Ok(None);
+        }
+        let mut value: [u8; N] = [0; N];
+        eeprom.read(address + HEADER_SIZE as u8, &mut value[..len])?;
+        if crc::update8(crc::crc8(&header[..2]), &value[..len]) != header[2] {
+            return Ok(None);
+        }
+
+        buf.get_mut(..len)
+            .ok_or(Error::OutOfBounds)?
+            .copy_from_slice(&value[..len]);
+        Ok(Some(len))
+    }
+
+    /// Set the value of `key`, waiting for the write cycles to complete.
+    ///
+    /// An existing record for `key` is overwritten, otherwise the record is
+    /// written to the first empty slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `key` is [`EMPTY`], if `data` is
+    /// longer than `N`, if there are no empty slots, or if the pages exceed
+    /// the EEPROM capacity.
+    pub fn set<SPI, CS, D, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+        key: u8,
+        data: &[u8],
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+        D: DelayUs<u32>,
+    {
+        if key == EMPTY || data.len() > N {
+            return Err(Error::OutOfBounds);
+        }
+        let slot: u16 = match self.find(eeprom, key)? {
+            Slot::Found(slot, _) | Slot::Empty(slot) => slot,
+            Slot::Full => return Err(Error::OutOfBounds),
+        };
+
+        let address: u8 = self.address(slot);
+        let mut header: [u8; HEADER_SIZE] = [key, data.len() as u8, 0];
+        header[2] = crc::update8(crc::crc8(&header[..2]), data);
+        eeprom.write_data(address + HEADER_SIZE as u8, data, delay)?;
+        eeprom.write_data(address, &header, delay)
+    }
+
+    /// Remove the record for `key`, waiting for the write cycle to complete.
+    ///
+    /// Returns `true` if a record was removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the pages exceed the EEPROM capacity.
+    pub fn remove<SPI, CS, D, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+        key: u8,
+        delay: &mut D,
+    ) -> Result<bool, Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+        D: DelayUs<u32>,
+    {
+        if key == EMPTY {
+            return Ok(false);
+        }
+        match self.find(eeprom, key)? {
+            Slot::Found(slot, _) => {
+                eeprom.write_data(self.address(slot), &[EMPTY; HEADER_SIZE], delay)?;
+                Ok(true)
+            }
+            Slot::Empty(_) | Slot::Full => Ok(false),
+        }
+    }
+}
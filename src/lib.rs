@@ -5,6 +5,9 @@
 //! the EEPROM, which is convenient for creating internet connected devices
 //! with valid MAC addresses.
 //!
+//! The 25AA02E64, which has an EUI-64 address instead, is supported with
+//! [`Eeprom25aa02e64`].
+//!
 //! # Example
 //!
 //! ```
@@ -26,19 +29,90 @@
 //! # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
 //! ```
 //!
+//! # Features
+//!
+//! * `async`: Asynchronous driver using [`embedded-hal-async`].
+//! * `bytemuck`: Reads and writes of [`bytemuck::Pod`] values.
+//! * `checked-errors` (default): Invalid addresses return [`Error::OutOfBounds`]
+//!   or [`Error::Misaligned`].
+//!   Without this feature invalid addresses are debug assertions, release builds
+//!   skip the checks and the EEPROM wraps the address.
+//! * `defmt`: Implementations of [`defmt::Format`] for the error type.
+//! * `double-buffered`: Power loss safe double buffered storage in the
+//!   [`double_buffered`] module.
+//! * `eh1`: Support for [`embedded-hal`] 1.0 SPI devices.
+//! * `embedded-storage`: Implementations of the [`embedded-storage`] traits.
+//! * `kv`: A key-value record store in the [`kv`] module.
+//! * `serde`: Implementations of the [`serde`] traits for [`Eui48`].
+//! * `sim`: Simulated EEPROM for host-side testing in the [`sim`] module.
+//! * `smoltcp`: Conversion from [`Eui48`] to [`smoltcp::wire::EthernetAddress`]
+//!   and IPv6 link-local addresses.
+//! * `ufmt`: Implementations of [`ufmt`] traits for [`Eui48`] and [`StatusRegister`].
+//! * `wear-leveling`: A wear leveled counter, record log, and
+//!   endurance tracker in the [`wear`] module.
+//!
+//! [`bytemuck::Pod`]: https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html
+//! [`defmt::Format`]: https://docs.rs/defmt/latest/defmt/trait.Format.html
 //! [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+//! [`embedded-hal-async`]: https://github.com/rust-embedded/embedded-hal/tree/master/embedded-hal-async
+//! [`embedded-storage`]: https://github.com/rust-embedded-community/embedded-storage
+//! [`serde`]: https://serde.rs
+//! [`smoltcp::wire::EthernetAddress`]: https://docs.rs/smoltcp/latest/smoltcp/wire/struct.EthernetAddress.html
+//! [`ufmt`]: https://docs.rs/ufmt
 //! [eeprom24x-rs]: https://github.com/eldruin/eeprom24x-rs
 //! [Microchip 25AA02E48]: http://ww1.microchip.com/downloads/en/DeviceDoc/25AA02E48-25AA02E64-2K-SPI-Bus-Serial-EEPROM-Data%20Sheet_DS20002123G.pdf
 #![doc(html_root_url = "https://docs.rs/eeprom25aa02e48/0.2.0")]
 #![deny(missing_docs, unsafe_code)]
 #![no_std]
 
-use embedded_hal as hal;
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::RangeInclusive;
+use delayed::DelayedEeprom;
+
+/// The [`embedded-hal`] 0.2 crate this driver is built against.
+///
+/// Using this re-export ensures the traits implemented by your HAL are from
+/// the same version of [`embedded-hal`] as the traits this driver uses.
+///
+/// [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+pub use embedded_hal as hal;
 
 use hal::blocking;
+use hal::blocking::delay::DelayUs;
 use hal::digital::v2::OutputPin;
 
+#[cfg(feature = "async")]
+pub mod asynch;
+mod crc;
+pub mod delayed;
+#[cfg(feature = "double-buffered")]
+pub mod double_buffered;
+#[cfg(feature = "eh1")]
+pub mod eh1;
+pub mod eui;
+pub mod hexdump;
+#[cfg(feature = "kv")]
+pub mod kv;
+mod plan;
+#[cfg(feature = "sim")]
+pub mod sim;
+pub mod status;
+#[cfg(feature = "embedded-storage")]
+pub mod storage;
+#[cfg(feature = "wear-leveling")]
+pub mod wear;
+
+pub use eui::{is_blank, validate_eui48, Eui48, Eui64, Identifier};
+pub use hexdump::HexDump;
+pub use plan::{PageWrite, WritePlan};
+pub use status::{BlockProtect, StatusRegister};
+
 /// EEPROM instructions.
+///
+/// These are used internally by the driver, and are public for custom
+/// instruction sequences with [`Eeprom25x::transaction`].
 pub mod instruction {
     /// Read data from memory array beginning at selected address.
     pub const READ: u8 = 0x03;
@@ -58,252 +132,4217 @@ pub mod instruction {
 pub const EUI48_BYTES: usize = 6;
 /// EPPROM memory address of the EUI48 address.
 pub const EUI48_MEMORY_ADDRESS: u8 = 0xFA;
+/// Number of bytes in an EUI64 address.
+pub const EUI64_BYTES: usize = 8;
+/// EEPROM memory address of the EUI64 address on the 25AA02E64.
+pub const EUI64_MEMORY_ADDRESS: u8 = 0xF8;
+/// Microchip organizationally unique identifier (OUI).
+pub const MICROCHIP_OUI: [u8; 3] = [0x00, 0x04, 0xA3];
 /// EEPROM page size in bytes.
 pub const PAGE_SIZE: u8 = 16;
+/// Number of pages in the 25AA02E48 and 25AA02E64.
+///
+/// See [`Eeprom25x::PAGE_COUNT`] for other geometries.
+pub const PAGE_COUNT: usize = Eeprom25aa02e48::<(), ()>::PAGE_COUNT;
+/// Default write cycle time in microseconds.
+///
+/// This is the 5 ms maximum write cycle time (T<sub>WC</sub>) from the
+/// datasheet, see [`with_write_cycle_time`] to change it.
+///
+/// [`with_write_cycle_time`]: Eeprom25x::with_write_cycle_time
+pub const WRITE_CYCLE_TIME_US: u32 = 5_000;
+/// SPI mode of the EEPROM.
+///
+/// The EEPROM supports SPI mode 0 and mode 3, configure the bus with this
+/// mode or [`MODE_3`](hal::spi::MODE_3).
+/// With any other mode reads return garbage, typically all `0xFF`.
+///
+/// The `eh1` and `asynch` modules have the same constant for
+/// [`embedded-hal`] 1.0 buses.
+///
+/// [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+pub const SPI_MODE: hal::spi::Mode = hal::spi::MODE_0;
 
-/// Microchip 25AA02E48 driver.
-#[derive(Default)]
-pub struct Eeprom25aa02e48<SPI, CS> {
+/// Address of the first byte in the page containing `address` on the
+/// 25AA02E48 and 25AA02E64.
+///
+/// See [`Eeprom25x::page_base`] for other geometries.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::page_base;
+///
+/// assert_eq!(page_base(0x00), 0x00);
+/// assert_eq!(page_base(0x1F), 0x10);
+/// ```
+pub const fn page_base(address: u8) -> u8 {
+    Eeprom25aa02e48::<(), ()>::page_base(address)
+}
+
+/// Offset of `address` from the start of its page on the 25AA02E48 and
+/// 25AA02E64.
+///
+/// See [`Eeprom25x::page_offset`] for other geometries.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::page_offset;
+///
+/// assert_eq!(page_offset(0x10), 0);
+/// assert_eq!(page_offset(0x1F), 15);
+/// ```
+pub const fn page_offset(address: u8) -> usize {
+    Eeprom25aa02e48::<(), ()>::page_offset(address)
+}
+
+/// Index of the page containing `address` on the 25AA02E48 and 25AA02E64.
+///
+/// See [`Eeprom25x::page_index`] for other geometries.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::page_index;
+///
+/// assert_eq!(page_index(0x0F), 0);
+/// assert_eq!(page_index(0xFF), 15);
+/// ```
+pub const fn page_index(address: u8) -> usize {
+    Eeprom25aa02e48::<(), ()>::page_index(address)
+}
+
+/// Iterator over the address of the first byte in every page of the
+/// 25AA02E48 and 25AA02E64.
+///
+/// See [`Eeprom25x::page_addresses`] for other geometries.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::{page_addresses, PAGE_COUNT};
+///
+/// let mut pages = page_addresses();
+/// assert_eq!(pages.next(), Some(0x00));
+/// assert_eq!(pages.next(), Some(0x10));
+/// assert_eq!(pages.last(), Some(0xF0));
+/// assert_eq!(page_addresses().count(), PAGE_COUNT);
+/// ```
+pub fn page_addresses() -> impl Iterator<Item = u8> + Clone {
+    Eeprom25aa02e48::<(), ()>::page_addresses()
+}
+
+/// A full page of data.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x20]),
+/// #   hal::spi::Transaction::write(vec![0x12; 16]),
+/// # ]);
+/// # let pin = hal::pin::Mock::new(&[
+/// #    hal::pin::Transaction::set(hal::pin::State::Low),
+/// #    hal::pin::Transaction::set(hal::pin::State::High),
+/// #    hal::pin::Transaction::set(hal::pin::State::Low),
+/// #    hal::pin::Transaction::set(hal::pin::State::High),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, Page};
+///
+/// let image: [u8; 64] = [0x12; 64];
+/// let page = Page::from_slice(&image[0x20..0x30])?;
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+/// eeprom.write_page(0x20, page.as_slice())?;
+/// # eeprom.free().0.done();
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Page(pub [u8; PAGE_SIZE as usize]);
+
+impl Page {
+    /// Copy a page from a slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the slice is not exactly
+    /// [`PAGE_SIZE`] bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::{Error, Page};
+    ///
+    /// assert_eq!(Page::from_slice::<(), ()>(&[0xAB; 16]), Ok(Page([0xAB; 16])));
+    /// assert_eq!(Page::from_slice::<(), ()>(&[0xAB; 15]), Err(Error::OutOfBounds));
+    /// ```
+    pub fn from_slice<SpiError, PinError>(data: &[u8]) -> Result<Page, Error<SpiError, PinError>> {
+        <[u8; PAGE_SIZE as usize]>::try_from(data)
+            .map(Page)
+            .map_err(|_| Error::OutOfBounds)
+    }
+}
+
+impl From<[u8; PAGE_SIZE as usize]> for Page {
+    fn from(data: [u8; PAGE_SIZE as usize]) -> Page {
+        Page(data)
+    }
+}
+
+impl From<Page> for [u8; PAGE_SIZE as usize] {
+    fn from(page: Page) -> [u8; PAGE_SIZE as usize] {
+        page.0
+    }
+}
+
+impl core::ops::Deref for Page {
+    type Target = [u8; PAGE_SIZE as usize];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Page {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Page {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Maximum size of the chunks passed to the [`read_stream`] callback.
+///
+/// [`read_stream`]: Eeprom25x::read_stream
+pub const STREAM_CHUNK: usize = 32;
+
+/// Interval between STATUS register polls when waiting for a write cycle.
+const POLL_INTERVAL_US: u32 = 100;
+
+/// Maximum number of bytes read in a single SPI transfer.
+///
+/// Some SPI drivers have a 255 byte limit on transfers, longer reads are
+/// split into multiple READ commands.
+const MAX_TRANSFER: usize = 255;
+
+/// Microchip 25xx SPI EEPROM driver.
+///
+/// The driver is generic over the EEPROM capacity and page size in bytes,
+/// which allows it to be used with other members of the 25xx family that
+/// have the same command set.
+///
+/// Addresses are 8 bits, the capacity may not exceed 256 bytes.
+/// Larger members of the family, such as the 25AA160 and 25LC256, use 16 bit
+/// addresses and are not supported, constructing a driver with a larger
+/// capacity is a compile time error.
+/// Supporting them would change the address type of every method and of the
+/// storage helpers built on the driver.
+///
+/// ```compile_fail
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[]);
+/// # let pin = hal::pin::Mock::new(&[]);
+/// use eeprom25aa02e48::Eeprom25x;
+///
+/// // 16 Kbit EEPROM with 16 bit addresses
+/// let eeprom: Eeprom25x<_, _, 2048, 16> = Eeprom25x::new(spi, pin);
+/// ```
+///
+/// The page size must divide the capacity, a partial last page is a compile
+/// time error.
+///
+/// ```compile_fail
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[]);
+/// # let pin = hal::pin::Mock::new(&[]);
+/// use eeprom25aa02e48::Eeprom25x;
+///
+/// let eeprom: Eeprom25x<_, _, 256, 24> = Eeprom25x::new(spi, pin);
+/// ```
+///
+/// `EUI` is [`Eui48`] or [`Eui64`] for EEPROMs with a factory programmed
+/// identifier, and `()` otherwise.
+///
+/// `WP` is the optional write protect pin, see [`new_with_wp`].
+///
+/// `HOLD` is the optional hold pin, see [`new_with_hold`].
+///
+/// # Example
+///
+/// ```
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[]);
+/// # let pin = hal::pin::Mock::new(&[]);
+/// use eeprom25aa02e48::Eeprom25x;
+///
+/// // 1 Kbit EEPROM with 16 byte pages
+/// type Eeprom25aa010a<SPI, CS> = Eeprom25x<SPI, CS, 128, 16>;
+///
+/// let mut eeprom: Eeprom25aa010a<_, _> = Eeprom25x::new(spi, pin);
+/// ```
+///
+/// [`new_with_wp`]: Self::new_with_wp
+/// [`new_with_hold`]: Self::new_with_hold
+pub struct Eeprom25x<
+    SPI,
+    CS,
+    const CAPACITY: usize,
+    const PAGE: usize,
+    EUI = (),
+    WP = (),
+    HOLD = (),
+> {
     /// SPI device.
     spi: SPI,
     /// GPIO for chip select.
     cs: CS,
+    /// GPIO for write protect, `()` if the pin is not driven by the driver.
+    wp: WP,
+    /// GPIO for hold, `()` if the pin is not driven by the driver.
+    hold: HOLD,
+    /// Active level of the chip select pin.
+    cs_polarity: CsPolarity,
+    /// Reject writes to the factory programmed identifier.
+    protect_eui: bool,
+    /// Write cycle time in microseconds.
+    write_cycle_time: u32,
+    /// Check for the write enable latch before writes, `None` if disabled.
+    verify_write_enable: Option<private::VerifyLatch<SPI, CS>>,
+    /// Factory programmed identifier.
+    eui: PhantomData<EUI>,
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD> Default
+    for Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    SPI: Default,
+    CS: Default,
+    WP: Default,
+    HOLD: Default,
+{
+    fn default() -> Self {
+        let () = Self::ADDRESS_FITS;
+        let () = Self::PAGE_DIVIDES;
+        Eeprom25x {
+            spi: SPI::default(),
+            cs: CS::default(),
+            wp: WP::default(),
+            hold: HOLD::default(),
+            cs_polarity: CsPolarity::ActiveLow,
+            protect_eui: true,
+            write_cycle_time: WRITE_CYCLE_TIME_US,
+            verify_write_enable: None,
+            eui: PhantomData,
+        }
+    }
+}
+
+/// Formats the driver configuration, the bus and pins are omitted to avoid
+/// requiring [`Debug`] implementations for them.
+///
+/// # Example
+///
+/// ```
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[]);
+/// # let pin = hal::pin::Mock::new(&[]);
+/// use eeprom25aa02e48::Eeprom25aa02e48;
+///
+/// let eeprom = Eeprom25aa02e48::new(spi, pin);
+/// assert_eq!(
+///     format!("{:?}", eeprom),
+///     "Eeprom25x { capacity: 256, page: 16, cs_polarity: ActiveLow, protect_eui: true, write_cycle_time: 5000, verify_write_enable: false, .. }"
+/// );
+/// ```
+///
+/// [`Debug`]: fmt::Debug
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD> fmt::Debug
+    for Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Eeprom25x")
+            .field("capacity", &CAPACITY)
+            .field("page", &PAGE)
+            .field("cs_polarity", &self.cs_polarity)
+            .field("protect_eui", &self.protect_eui)
+            .field("write_cycle_time", &self.write_cycle_time)
+            .field("verify_write_enable", &self.verify_write_enable.is_some())
+            .finish_non_exhaustive()
+    }
 }
 
+/// Microchip 25AA02E48 driver.
+pub type Eeprom25aa02e48<SPI, CS, WP = (), HOLD = ()> =
+    Eeprom25x<SPI, CS, 256, { PAGE_SIZE as usize }, Eui48, WP, HOLD>;
+
+/// Microchip 25AA02E64 driver.
+pub type Eeprom25aa02e64<SPI, CS, WP = (), HOLD = ()> =
+    Eeprom25x<SPI, CS, 256, { PAGE_SIZE as usize }, Eui64, WP, HOLD>;
+
 /// Error type.
-#[derive(Debug)]
+///
+/// New variants may be added in minor releases, matches must have a wildcard
+/// arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum Error<SpiError, PinError> {
     /// SPI bus error wrapper.
+    ///
+    /// The chip select pin is deasserted before the error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{hal::blocking::spi, Eeprom25aa02e48, Error};
+    ///
+    /// # struct FailingSpi;
+    /// # impl spi::Write<u8> for FailingSpi {
+    /// #     type Error = ();
+    /// #     fn write(&mut self, _: &[u8]) -> Result<(), ()> { Err(()) }
+    /// # }
+    /// # impl spi::Transfer<u8> for FailingSpi {
+    /// #     type Error = ();
+    /// #     fn transfer<'w>(&mut self, _: &'w mut [u8]) -> Result<&'w [u8], ()> { Err(()) }
+    /// # }
+    /// // SPI bus that fails every transfer
+    /// let mut eeprom = Eeprom25aa02e48::new(FailingSpi, pin);
+    /// assert_eq!(eeprom.read_byte(0x00), Err(Error::Spi(())));
+    /// // the pin mock checks that chip select was driven high
+    /// eeprom.free().1.done();
+    /// ```
     Spi(SpiError),
     /// GPIO pin error wrapper.
     Pin(PinError),
+    /// Address range exceeds the bounds of the memory array or page.
+    OutOfBounds,
+    /// Address is not page aligned.
+    Misaligned,
+    /// EUI-48 does not start with the expected OUI.
+    InvalidOui,
+    /// Write cycle did not complete within the allowed number of polls.
+    Timeout,
+    /// Write overlaps the factory programmed identifier.
+    ///
+    /// See [`Eeprom25x::protect_eui`].
+    ProtectedRegion,
+    /// CRC-8 stored in a page does not match the data in the page.
+    ///
+    /// See [`Eeprom25x::read_page_checked`].
+    CrcMismatch,
+    /// Data read back after a write does not match the data written.
+    VerifyFailed {
+        /// Address of the first mismatched byte.
+        address: u8,
+    },
+    /// Write enable latch is not set after a WREN instruction.
+    ///
+    /// See [`Eeprom25x::write_enable_verified`].
+    WriteEnableFailed,
+    /// STATUS register read is not plausible, the EEPROM did not respond.
+    ///
+    /// See [`Eeprom25x::try_new_verified`].
+    NoResponse,
+}
+
+impl<SpiError: fmt::Debug, PinError: fmt::Debug> fmt::Display for Error<SpiError, PinError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Spi(e) => write!(f, "SPI bus error: {:?}", e),
+            Error::Pin(e) => write!(f, "GPIO pin error: {:?}", e),
+            Error::OutOfBounds => f.write_str("address out of bounds"),
+            Error::Misaligned => f.write_str("address is not page aligned"),
+            Error::InvalidOui => f.write_str("EUI-48 has an unexpected OUI"),
+            Error::Timeout => f.write_str("timed out waiting for the write cycle"),
+            Error::ProtectedRegion => {
+                f.write_str("write overlaps the factory programmed identifier")
+            }
+            Error::CrcMismatch => f.write_str("page CRC-8 mismatch"),
+            Error::VerifyFailed { address } => {
+                write!(f, "verification failed at address 0x{:02X}", address)
+            }
+            Error::WriteEnableFailed => f.write_str("write enable latch is not set"),
+            Error::NoResponse => f.write_str("EEPROM did not respond"),
+        }
+    }
+}
+
+impl<E> Error<E, E> {
+    /// SPI bus or GPIO pin error, for HALs that use the same error type for
+    /// both.
+    ///
+    /// Returns `None` for errors that do not wrap a HAL error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Error;
+    ///
+    /// let spi: Error<u8, u8> = Error::Spi(1);
+    /// assert_eq!(spi.hal_error(), Some(1));
+    /// let pin: Error<u8, u8> = Error::Pin(2);
+    /// assert_eq!(pin.hal_error(), Some(2));
+    /// let oob: Error<u8, u8> = Error::OutOfBounds;
+    /// assert_eq!(oob.hal_error(), None);
+    /// ```
+    pub fn hal_error(self) -> Option<E> {
+        match self {
+            Error::Spi(e) | Error::Pin(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl<SpiError, PinError> core::error::Error for Error<SpiError, PinError>
+where
+    SpiError: core::error::Error + 'static,
+    PinError: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Spi(e) => Some(e),
+            Error::Pin(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+mod private {
+    use super::{CsPolarity, Error, WriteInterface};
+
+    pub trait Sealed<CS> {}
+    pub trait SealedIdentifier {}
+
+    /// Reads the write enable latch into the result.
+    pub type VerifyLatch<SPI, CS> =
+        fn(&mut SPI, &mut CS, CsPolarity, &mut dyn LatchResult<SPI, CS>);
+
+    /// Result of reading the write enable latch.
+    ///
+    /// This carries the error types of the SPI interface through the
+    /// verifier stored in the driver, which cannot name them.
+    pub trait LatchResult<SPI, CS> {
+        fn set(&mut self, result: Result<bool, Error<SPI::SpiError, SPI::PinError>>)
+        where
+            SPI: WriteInterface<CS>;
+    }
+
+    impl<SPI, CS, SpiError, PinError> LatchResult<SPI, CS> for Result<bool, Error<SpiError, PinError>>
+    where
+        SPI: WriteInterface<CS, SpiError = SpiError, PinError = PinError>,
+    {
+        fn set(&mut self, result: Result<bool, Error<SpiError, PinError>>) {
+            *self = result;
+        }
+    }
+}
+
+/// Check the arguments of a driver method.
+///
+/// With the `checked-errors` feature this returns `error` if `valid` is
+/// `false`.
+/// Without the feature this is a debug assertion, and the method proceeds
+/// with the invalid arguments in release builds.
+#[inline(always)]
+pub(crate) fn check_args<SpiError, PinError>(
+    valid: bool,
+    error: Error<SpiError, PinError>,
+) -> Result<(), Error<SpiError, PinError>> {
+    if cfg!(feature = "checked-errors") {
+        if valid {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    } else {
+        debug_assert!(
+            valid,
+            "{}",
+            match error {
+                Error::Misaligned => "address is not page aligned",
+                _ => "address out of bounds",
+            }
+        );
+        Ok(())
+    }
+}
+
+/// Active level of the chip select pin.
+///
+/// The EEPROM chip select is active low, [`ActiveHigh`] is for boards with
+/// an inverter between the pin and the EEPROM.
+///
+/// This has no effect on drivers created from a SPI device with the `eh1`
+/// feature, the SPI device manages chip select.
+///
+/// [`ActiveHigh`]: CsPolarity::ActiveHigh
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CsPolarity {
+    /// Chip select is driven low to select the EEPROM.
+    #[default]
+    ActiveLow,
+    /// Chip select is driven high to select the EEPROM.
+    ActiveHigh,
+}
+
+/// Write-only SPI interface to the EEPROM.
+///
+/// This is implemented for [`embedded-hal`] 0.2 SPI buses that only
+/// implement [`blocking::spi::Write`], which is sufficient for the write
+/// methods that do not read from the EEPROM, such as
+/// [`Eeprom25x::write_page`].
+///
+/// This trait is sealed, it cannot be implemented outside of this crate.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x00]),
+/// #   hal::spi::Transaction::write(vec![0x12, 0x34]),
+/// # ]);
+/// # let pin = hal::pin::Mock::new(&[
+/// #   hal::pin::Transaction::set(hal::pin::State::Low),
+/// #   hal::pin::Transaction::set(hal::pin::State::High),
+/// #   hal::pin::Transaction::set(hal::pin::State::Low),
+/// #   hal::pin::Transaction::set(hal::pin::State::High),
+/// # ]);
+/// use eeprom25aa02e48::Eeprom25aa02e48;
+/// use embedded_hal::blocking::spi::Write;
+///
+/// // half-duplex SPI bus that cannot read
+/// struct WriteOnly(hal::spi::Mock);
+///
+/// impl Write<u8> for WriteOnly {
+///     type Error = hal::MockError;
+///
+///     fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+///         self.0.write(words)
+///     }
+/// }
+///
+/// let mut eeprom = Eeprom25aa02e48::new(WriteOnly(spi), pin);
+/// eeprom.write_page(0x00, &[0x12, 0x34])?;
+/// # eeprom.free().0 .0.done();
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+///
+/// [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+pub trait WriteInterface<CS>: private::Sealed<CS> {
+    /// SPI error type.
+    type SpiError;
+    /// Chip select pin error type.
+    type PinError;
+
+    /// Write `header` followed by `data` in a single chip select frame.
+    fn write_frame(
+        &mut self,
+        cs: &mut CS,
+        polarity: CsPolarity,
+        header: &[u8],
+        data: &[u8],
+    ) -> Result<(), Error<Self::SpiError, Self::PinError>>;
+}
+
+/// SPI interface to the EEPROM.
+///
+/// This is implemented for [`embedded-hal`] 0.2 SPI buses paired with a chip
+/// select pin, and for [`embedded-hal`] 1.0 SPI devices with the `eh1`
+/// feature.
+///
+/// This trait is sealed, it cannot be implemented outside of this crate.
+///
+/// [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+pub trait Interface<CS>: WriteInterface<CS> {
+    /// Write `header` then read into `buf` in a single chip select frame.
+    fn read_frame(
+        &mut self,
+        cs: &mut CS,
+        polarity: CsPolarity,
+        header: &[u8],
+        buf: &mut [u8],
+    ) -> Result<(), Error<Self::SpiError, Self::PinError>>;
+
+    /// Transfer `buf` in-place in a single chip select frame.
+    fn transfer_frame(
+        &mut self,
+        cs: &mut CS,
+        polarity: CsPolarity,
+        buf: &mut [u8],
+    ) -> Result<(), Error<Self::SpiError, Self::PinError>>;
+}
+
+impl<SPI, CS, SpiError, PinError> private::Sealed<CS> for SPI
+where
+    SPI: blocking::spi::Write<u8, Error = SpiError>,
+    CS: OutputPin<Error = PinError>,
+{
+}
+
+/// Drive the chip select pin to the active level.
+#[inline(always)]
+fn chip_enable<CS: OutputPin>(cs: &mut CS, polarity: CsPolarity) -> Result<(), CS::Error> {
+    match polarity {
+        CsPolarity::ActiveLow => cs.set_low(),
+        CsPolarity::ActiveHigh => cs.set_high(),
+    }
+}
+
+/// Drive the chip select pin to the idle level.
+#[inline(always)]
+fn chip_disable<CS: OutputPin>(cs: &mut CS, polarity: CsPolarity) -> Result<(), CS::Error> {
+    match polarity {
+        CsPolarity::ActiveLow => cs.set_high(),
+        CsPolarity::ActiveHigh => cs.set_low(),
+    }
+}
+
+/// Asserted chip select pin, deasserted when dropped.
+///
+/// [`release`](Self::release) deasserts the pin and returns the pin error,
+/// dropping the guard on an early return or panic deasserts the pin and
+/// ignores the pin error.
+struct ChipSelect<'a, CS: OutputPin> {
+    /// Chip select pin.
+    cs: &'a mut CS,
+    /// Active level of the chip select pin.
+    polarity: CsPolarity,
+}
+
+impl<'a, CS: OutputPin> ChipSelect<'a, CS> {
+    /// Assert the chip select pin.
+    #[inline(always)]
+    fn assert(cs: &'a mut CS, polarity: CsPolarity) -> Result<Self, CS::Error> {
+        chip_enable(cs, polarity)?;
+        Ok(ChipSelect { cs, polarity })
+    }
+
+    /// Deassert the chip select pin.
+    #[inline(always)]
+    fn release(self) -> Result<(), CS::Error> {
+        let mut guard = core::mem::ManuallyDrop::new(self);
+        let polarity: CsPolarity = guard.polarity;
+        chip_disable(guard.cs, polarity)
+    }
+}
+
+impl<CS: OutputPin> Drop for ChipSelect<'_, CS> {
+    fn drop(&mut self) {
+        chip_disable(self.cs, self.polarity).ok();
+    }
+}
+
+/// Context manager to ensure CS is always deasserted after an operation.
+#[inline(always)]
+fn with_chip_enable<SPI, CS, SpiError, PinError, R, F>(
+    spi: &mut SPI,
+    cs: &mut CS,
+    polarity: CsPolarity,
+    f: F,
+) -> Result<R, Error<SpiError, PinError>>
+where
+    CS: OutputPin<Error = PinError>,
+    F: FnOnce(&mut SPI) -> Result<R, SpiError>,
+{
+    let guard = ChipSelect::assert(cs, polarity).map_err(Error::Pin)?;
+    let result = f(spi).map_err(Error::Spi);
+    guard.release().map_err(Error::Pin)?;
+    result
+}
+
+impl<SPI, CS, SpiError, PinError> WriteInterface<CS> for SPI
+where
+    SPI: blocking::spi::Write<u8, Error = SpiError>,
+    CS: OutputPin<Error = PinError>,
+{
+    type SpiError = SpiError;
+    type PinError = PinError;
+
+    fn write_frame(
+        &mut self,
+        cs: &mut CS,
+        polarity: CsPolarity,
+        header: &[u8],
+        data: &[u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        with_chip_enable(self, cs, polarity, |spi| {
+            spi.write(header)?;
+            if !data.is_empty() {
+                spi.write(data)?;
+            }
+            Ok(())
+        })
+    }
 }
 
-impl<SPI, CS, SpiError, PinError> Eeprom25aa02e48<SPI, CS>
+impl<SPI, CS, SpiError, PinError> Interface<CS> for SPI
 where
     SPI: blocking::spi::Transfer<u8, Error = SpiError> + blocking::spi::Write<u8, Error = SpiError>,
     CS: OutputPin<Error = PinError>,
 {
-    /// Creates a new driver from a SPI bus and a chip select digital I/O pin.
+    fn read_frame(
+        &mut self,
+        cs: &mut CS,
+        polarity: CsPolarity,
+        header: &[u8],
+        buf: &mut [u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        with_chip_enable(self, cs, polarity, |spi| {
+            spi.write(header)?;
+            spi.transfer(buf)?;
+            Ok(())
+        })
+    }
+
+    fn transfer_frame(
+        &mut self,
+        cs: &mut CS,
+        polarity: CsPolarity,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        with_chip_enable(self, cs, polarity, |spi| {
+            spi.transfer(buf)?;
+            Ok(())
+        })
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>
+{
+    /// Compile time check that every address fits in the single address
+    /// byte of the READ and WRITE instructions.
+    pub(crate) const ADDRESS_FITS: () = assert!(
+        CAPACITY <= 256,
+        "capacity exceeds the 8 bit address of the READ and WRITE instructions"
+    );
+
+    /// Compile time check that the memory array is a whole number of pages.
+    pub(crate) const PAGE_DIVIDES: () = assert!(
+        PAGE != 0 && CAPACITY.is_multiple_of(PAGE),
+        "page size does not divide the capacity"
+    );
+
+    /// Number of pages in the memory array.
     ///
-    /// # Safety
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eeprom25x;
+    ///
+    /// type Eeprom25aa010a = Eeprom25x<(), (), 128, 16>;
+    /// type Eeprom25aa040a = Eeprom25x<(), (), 256, 16>;
+    ///
+    /// assert_eq!(Eeprom25aa010a::PAGE_COUNT, 8);
+    /// assert_eq!(Eeprom25aa040a::PAGE_COUNT, 16);
+    /// ```
+    pub const PAGE_COUNT: usize = CAPACITY / PAGE;
+
+    /// Address of the first byte in the page containing `address`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eeprom25x;
+    ///
+    /// type Eeprom = Eeprom25x<(), (), 128, 8>;
+    ///
+    /// assert_eq!(Eeprom::page_base(0x00), 0x00);
+    /// assert_eq!(Eeprom::page_base(0x1F), 0x18);
+    /// ```
+    pub const fn page_base(address: u8) -> u8 {
+        address - Self::page_offset(address) as u8
+    }
+
+    /// Offset of `address` from the start of its page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eeprom25x;
+    ///
+    /// type Eeprom = Eeprom25x<(), (), 128, 8>;
+    ///
+    /// assert_eq!(Eeprom::page_offset(0x18), 0);
+    /// assert_eq!(Eeprom::page_offset(0x1F), 7);
+    /// ```
+    pub const fn page_offset(address: u8) -> usize {
+        address as usize % PAGE
+    }
+
+    /// Index of the page containing `address`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eeprom25x;
+    ///
+    /// type Eeprom = Eeprom25x<(), (), 128, 8>;
+    ///
+    /// assert_eq!(Eeprom::page_index(0x07), 0);
+    /// assert_eq!(Eeprom::page_index(0x7F), 15);
+    /// ```
+    pub const fn page_index(address: u8) -> usize {
+        address as usize / PAGE
+    }
+
+    /// Iterator over the address of the first byte in every page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eeprom25x;
+    ///
+    /// type Eeprom = Eeprom25x<(), (), 128, 8>;
+    ///
+    /// let mut pages = Eeprom::page_addresses();
+    /// assert_eq!(pages.next(), Some(0x00));
+    /// assert_eq!(pages.next(), Some(0x08));
+    /// assert_eq!(pages.last(), Some(0x78));
+    /// assert_eq!(Eeprom::page_addresses().count(), Eeprom::PAGE_COUNT);
+    /// ```
+    pub fn page_addresses() -> impl Iterator<Item = u8> + Clone {
+        (0..CAPACITY).step_by(PAGE).map(|address| address as u8)
+    }
+
+    /// Mutable reference to the SPI bus.
+    ///
+    /// This can be used to reconfigure the SPI bus without freeing the
+    /// driver.
+    /// The caller is responsible for leaving the bus in a state the EEPROM
+    /// can communicate with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let spi = eeprom.spi_mut();
+    /// ```
+    pub fn spi_mut(&mut self) -> &mut SPI {
+        &mut self.spi
+    }
+
+    /// Replace the SPI bus, returning the previous bus.
+    ///
+    /// The chip select pin and the driver configuration are kept, this can
+    /// be used to reconfigure a shared bus without freeing the driver.
+    ///
+    /// Driver methods always return with the chip select pin high, the bus
+    /// must not be replaced in the middle of a sequence built on the raw
+    /// instructions, such as between [`write_enable`] and a [`transaction`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let other_spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let spi = eeprom.replace_spi(other_spi);
+    /// ```
+    ///
+    /// [`write_enable`]: Self::write_enable
+    /// [`transaction`]: Self::transaction
+    pub fn replace_spi(&mut self, spi: SPI) -> SPI {
+        core::mem::replace(&mut self.spi, spi)
+    }
+
+    /// Mutable reference to the CS pin.
+    ///
+    /// The caller is responsible for leaving the pin high, driving the pin
+    /// low outside of a driver method will corrupt the next instruction.
+    pub fn cs_mut(&mut self) -> &mut CS {
+        &mut self.cs
+    }
+
+    /// Capacity of the memory array in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, PAGE_COUNT};
+    ///
+    /// let eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// assert_eq!(eeprom.capacity(), 256);
+    /// assert_eq!(eeprom.page_count(), PAGE_COUNT);
+    /// ```
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Number of pages in the memory array.
+    pub const fn page_count(&self) -> usize {
+        Self::PAGE_COUNT
+    }
+
+    /// Enable or disable the guard against writes to the factory programmed
+    /// identifier.
+    ///
+    /// The identifier is stored at the end of the memory array, `0xFA` to
+    /// `0xFF` for the EUI-48 and `0xF8` to `0xFF` for the EUI-64.
+    /// When the guard is enabled writes overlapping the identifier return
+    /// [`Error::ProtectedRegion`] without writing anything, and [`erase`]
+    /// stops before the identifier.
+    ///
+    /// The guard is enabled by default, it has no effect if `EUI` is `()`.
+    /// Reads are never affected by the guard.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0xF0]),
+    /// #   hal::spi::Transaction::write(vec![0x00; 16]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #   hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #   hal::pin::Transaction::set(hal::pin::State::High),
+    /// #   hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #   hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let data: [u8; 16] = [0x00; 16];
+    /// let result = eeprom.write_page(0xF0, &data);
+    /// assert!(matches!(result, Err(Error::ProtectedRegion)));
+    ///
+    /// eeprom.protect_eui(false);
+    /// eeprom.write_page(0xF0, &data)?;
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`erase`]: Self::erase
+    #[doc(alias = "protect_eui48")]
+    pub fn protect_eui(&mut self, enabled: bool) {
+        self.protect_eui = enabled;
+    }
+
+    /// Set the write cycle time in microseconds.
+    ///
+    /// The default is [`WRITE_CYCLE_TIME_US`], the maximum from the
+    /// datasheet.
+    /// The write cycle time varies with temperature and supply voltage, a
+    /// shorter time may be used if the EEPROM is characterized for the
+    /// application.
+    ///
+    /// This is the delay of [`write_page_blocking_fixed`], and the initial
+    /// delay of [`wait_ready`] and [`wait_ready_timeout`] before the first
+    /// read of the STATUS register.
+    /// A time of `0` polls the STATUS register immediately.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, WRITE_CYCLE_TIME_US};
+    ///
+    /// let eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// assert_eq!(eeprom.write_cycle_time(), WRITE_CYCLE_TIME_US);
+    ///
+    /// let eeprom = eeprom.with_write_cycle_time(6_000);
+    /// assert_eq!(eeprom.write_cycle_time(), 6_000);
+    /// ```
+    ///
+    /// [`write_page_blocking_fixed`]: Self::write_page_blocking_fixed
+    /// [`wait_ready`]: Self::wait_ready
+    /// [`wait_ready_timeout`]: Self::wait_ready_timeout
+    pub fn with_write_cycle_time(mut self, us: u32) -> Self {
+        self.write_cycle_time = us;
+        self
+    }
+
+    /// Write cycle time in microseconds.
+    ///
+    /// See [`with_write_cycle_time`] for details.
+    ///
+    /// [`with_write_cycle_time`]: Self::with_write_cycle_time
+    pub const fn write_cycle_time(&self) -> u32 {
+        self.write_cycle_time
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    EUI: Identifier,
+{
+    /// First address of the factory programmed identifier if the guard is
+    /// enabled, `CAPACITY` otherwise.
+    fn protected_start(&self) -> usize {
+        if self.protect_eui {
+            // the identifier occupies the last bytes of the memory array
+            CAPACITY.saturating_sub(EUI::BYTES)
+        } else {
+            CAPACITY
+        }
+    }
+
+    /// Check that a write of `len` bytes at `address` does not overlap the
+    /// factory programmed identifier.
+    fn check_protected<SpiError, PinError>(
+        &self,
+        address: u8,
+        len: usize,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        if len != 0 && usize::from(address) + len > self.protected_start() {
+            Err(Error::ProtectedRegion)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI>
+{
+    /// Free the SPI bus and CS pin from the driver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let (spi, pin) = eeprom.free();
+    /// ```
+    pub fn free(self) -> (SPI, CS) {
+        (self.spi, self.cs)
+    }
+
+    /// Free the SPI bus and CS pin from the driver.
+    ///
+    /// This is an alias of [`free`].
+    ///
+    /// [`free`]: Self::free
+    pub fn into_inner(self) -> (SPI, CS) {
+        self.free()
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, HOLD>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, (), HOLD>
+{
+    /// Add a write protect pin to the driver.
+    ///
+    /// See [`new_with_wp`] for details.
+    ///
+    /// [`new_with_wp`]: Eeprom25x::new_with_wp
+    pub fn with_wp<WP>(self, wp: WP) -> Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD> {
+        Eeprom25x {
+            spi: self.spi,
+            cs: self.cs,
+            wp,
+            hold: self.hold,
+            cs_polarity: self.cs_polarity,
+            protect_eui: self.protect_eui,
+            write_cycle_time: self.write_cycle_time,
+            verify_write_enable: self.verify_write_enable,
+            eui: PhantomData,
+        }
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, WP>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP>
+where
+    WP: OutputPin,
+{
+    /// Creates a new driver from a SPI bus, a chip select digital I/O pin,
+    /// and a write protect digital I/O pin.
+    ///
+    /// When the write protect pin is low writes to the memory array and the
+    /// STATUS register are inhibited by the EEPROM, and the driver does not
+    /// report an error for writes that are ignored.
+    /// Use [`hw_unprotect`] before writing and [`hw_protect`] afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// # let wp = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new_with_wp(spi, pin, wp);
+    /// eeprom.hw_unprotect()?;
+    /// // write to the EEPROM
+    /// eeprom.hw_protect()?;
+    /// # Ok::<(), hal::MockError>(())
+    /// ```
+    ///
+    /// [`hw_unprotect`]: Self::hw_unprotect
+    /// [`hw_protect`]: Self::hw_protect
+    pub const fn new_with_wp(spi: SPI, cs: CS, wp: WP) -> Self {
+        let () = Self::ADDRESS_FITS;
+        let () = Self::PAGE_DIVIDES;
+        Eeprom25x {
+            spi,
+            cs,
+            wp,
+            hold: (),
+            cs_polarity: CsPolarity::ActiveLow,
+            protect_eui: true,
+            write_cycle_time: WRITE_CYCLE_TIME_US,
+            verify_write_enable: None,
+            eui: PhantomData,
+        }
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    WP: OutputPin,
+{
+    /// Drive the write protect pin low, inhibiting writes.
+    pub fn hw_protect(&mut self) -> Result<(), WP::Error> {
+        self.wp.set_low()
+    }
+
+    /// Drive the write protect pin high, allowing writes.
+    pub fn hw_unprotect(&mut self) -> Result<(), WP::Error> {
+        self.wp.set_high()
+    }
+
+    /// Remove the write protect pin from the driver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// # let wp = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let eeprom = Eeprom25aa02e48::new_with_wp(spi, pin, wp);
+    /// let (eeprom, wp) = eeprom.split_wp();
+    /// let (spi, pin) = eeprom.free();
+    /// ```
+    pub fn split_wp(self) -> (Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, (), HOLD>, WP) {
+        (
+            Eeprom25x {
+                spi: self.spi,
+                cs: self.cs,
+                wp: (),
+                hold: self.hold,
+                cs_polarity: self.cs_polarity,
+                protect_eui: self.protect_eui,
+                write_cycle_time: self.write_cycle_time,
+                verify_write_enable: self.verify_write_enable,
+                eui: PhantomData,
+            },
+            self.wp,
+        )
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, WP>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, ()>
+{
+    /// Add a hold pin to the driver.
+    ///
+    /// See [`new_with_hold`] for details.
+    ///
+    /// [`new_with_hold`]: Eeprom25x::new_with_hold
+    pub fn with_hold<HOLD>(self, hold: HOLD) -> Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD> {
+        Eeprom25x {
+            spi: self.spi,
+            cs: self.cs,
+            wp: self.wp,
+            hold,
+            cs_polarity: self.cs_polarity,
+            protect_eui: self.protect_eui,
+            write_cycle_time: self.write_cycle_time,
+            verify_write_enable: self.verify_write_enable,
+            eui: PhantomData,
+        }
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, HOLD>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, (), HOLD>
+where
+    HOLD: OutputPin,
+{
+    /// Creates a new driver from a SPI bus, a chip select digital I/O pin,
+    /// and a hold digital I/O pin.
+    ///
+    /// Driving the hold pin low while the chip select is low pauses the
+    /// transaction in progress, the EEPROM ignores the SCK and SI inputs
+    /// until the hold pin is driven high.
+    /// This allows the SPI bus to be used by other devices in the middle of
+    /// a transaction.
+    ///
+    /// The hold pin must only change state while SCK is low.
+    /// The driver does not control SCK, it is up to the caller to ensure
+    /// [`hold`] and [`resume`] are called between clock edges.
+    ///
+    /// The hold pin must be high before being passed to this function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// # let hold = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new_with_hold(spi, pin, hold);
+    /// eeprom.hold()?;
+    /// // use the SPI bus for another device
+    /// eeprom.resume()?;
+    /// # Ok::<(), hal::MockError>(())
+    /// ```
+    ///
+    /// [`hold`]: Self::hold
+    /// [`resume`]: Self::resume
+    pub const fn new_with_hold(spi: SPI, cs: CS, hold: HOLD) -> Self {
+        let () = Self::ADDRESS_FITS;
+        let () = Self::PAGE_DIVIDES;
+        Eeprom25x {
+            spi,
+            cs,
+            wp: (),
+            hold,
+            cs_polarity: CsPolarity::ActiveLow,
+            protect_eui: true,
+            write_cycle_time: WRITE_CYCLE_TIME_US,
+            verify_write_enable: None,
+            eui: PhantomData,
+        }
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    HOLD: OutputPin,
+{
+    /// Drive the hold pin low, pausing the transaction in progress.
+    ///
+    /// This must only be called while SCK is low.
+    pub fn hold(&mut self) -> Result<(), HOLD::Error> {
+        self.hold.set_low()
+    }
+
+    /// Drive the hold pin high, resuming the paused transaction.
+    ///
+    /// This must only be called while SCK is low.
+    pub fn resume(&mut self) -> Result<(), HOLD::Error> {
+        self.hold.set_high()
+    }
+
+    /// Remove the hold pin from the driver.
+    pub fn split_hold(self) -> (Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP>, HOLD) {
+        (
+            Eeprom25x {
+                spi: self.spi,
+                cs: self.cs,
+                wp: self.wp,
+                hold: (),
+                cs_polarity: self.cs_polarity,
+                protect_eui: self.protect_eui,
+                write_cycle_time: self.write_cycle_time,
+                verify_write_enable: self.verify_write_enable,
+                eui: PhantomData,
+            },
+            self.hold,
+        )
+    }
+}
+
+impl<SPI, CS, SpiError, PinError, const CAPACITY: usize, const PAGE: usize, EUI>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI>
+where
+    SPI: WriteInterface<CS, SpiError = SpiError, PinError = PinError>,
+{
+    /// Creates a new driver from a SPI bus and a chip select digital I/O pin.
+    ///
+    /// This is a `const fn`, which allows the driver to be created in a
+    /// `static` initializer.
+    ///
+    /// # Safety
+    ///
+    /// The chip select pin must be high before being passed to this function,
+    /// [`try_new`] drives the pin high.
+    ///
+    /// # Example
+    ///
+    /// The `spi` and `pin` variables in this example will be provided by your
+    /// device-specific hal crate.
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let mut pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    /// use embedded_hal::digital::v2::OutputPin;
+    ///
+    /// pin.set_high()?;
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// # Ok::<(), hal::MockError>(())
+    /// ```
+    ///
+    /// [`try_new`]: Self::try_new
+    pub const fn new(spi: SPI, cs: CS) -> Self {
+        Self::new_with_cs_polarity(spi, cs, CsPolarity::ActiveLow)
+    }
+
+    /// Creates a new driver from a SPI bus and a chip select digital I/O pin
+    /// with the given chip select polarity.
+    ///
+    /// The chip select pin must be at the idle level before being passed to
+    /// this function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// # ]);
+    /// use eeprom25aa02e48::{CsPolarity, Eeprom25aa02e48};
+    ///
+    /// // chip select is inverted on this board
+    /// let mut eeprom = Eeprom25aa02e48::new_with_cs_polarity(spi, pin, CsPolarity::ActiveHigh);
+    /// eeprom.write_enable()?;
+    /// # eeprom.free().1.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub const fn new_with_cs_polarity(spi: SPI, cs: CS, cs_polarity: CsPolarity) -> Self {
+        let () = Self::ADDRESS_FITS;
+        let () = Self::PAGE_DIVIDES;
+        Eeprom25x {
+            spi,
+            cs,
+            wp: (),
+            hold: (),
+            cs_polarity,
+            protect_eui: true,
+            write_cycle_time: WRITE_CYCLE_TIME_US,
+            verify_write_enable: None,
+            eui: PhantomData,
+        }
+    }
+}
+
+impl<SPI, CS, SpiError, PinError, const CAPACITY: usize, const PAGE: usize, EUI>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI>
+where
+    SPI: WriteInterface<CS, SpiError = SpiError, PinError = PinError>,
+    CS: OutputPin<Error = PinError>,
+{
+    /// Creates a new driver from a SPI bus and a chip select digital I/O pin,
+    /// driving the chip select pin high.
+    ///
+    /// This guarantees the chip select pin is idle before the first
+    /// instruction, regardless of the state of the pin after reset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::try_new(spi, pin)?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Pin`] if the chip select pin cannot be driven high.
+    pub fn try_new(spi: SPI, mut cs: CS) -> Result<Self, Error<SpiError, PinError>> {
+        cs.set_high().map_err(Error::Pin)?;
+        Ok(Self::new(spi, cs))
+    }
+}
+
+impl<SPI, CS, SpiError, PinError, const CAPACITY: usize, const PAGE: usize, EUI>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI>
+where
+    SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+    CS: OutputPin<Error = PinError>,
+    EUI: Identifier,
+{
+    /// Creates a new driver with [`try_new`], and reads the STATUS register
+    /// to check that the EEPROM responds.
+    ///
+    /// The unimplemented bits of the STATUS register always read as `0`, a
+    /// read with any of these bits set, such as `0xFF` from a disconnected
+    /// bus with a pull-up, fails the check.
+    /// A bus that reads all `0x00` passes the check, use [`probe`] on the
+    /// 25AA02E48 for a stronger check.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::try_new_verified(spi, pin)?;
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoResponse`] if the STATUS register read is not
+    /// plausible, the SPI bus and chip select pin are dropped.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0xFF, 0xFF]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// // disconnected
+    /// let result = Eeprom25aa02e48::try_new_verified(spi, pin);
+    /// assert!(matches!(result, Err(Error::NoResponse)));
+    /// ```
+    ///
+    /// [`try_new`]: Self::try_new
+    /// [`probe`]: Eeprom25aa02e48::probe
+    pub fn try_new_verified(spi: SPI, cs: CS) -> Result<Self, Error<SpiError, PinError>> {
+        let mut eeprom: Self = Self::try_new(spi, cs)?;
+        let status: u8 = eeprom.read_status_register()?.into();
+        if status & StatusRegister::UNIMPLEMENTED == 0 {
+            Ok(eeprom)
+        } else {
+            Err(Error::NoResponse)
+        }
+    }
+}
+
+impl<SPI, CS, PinError, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    CS: OutputPin<Error = PinError>,
+{
+    /// Run `f` with raw access to the SPI bus in a single chip select frame.
+    ///
+    /// Chip select is asserted before calling `f`, and deasserted after `f`
+    /// returns, including when `f` returns an error.
+    ///
+    /// Like [`transaction`] this bypasses all driver logic, the caller is
+    /// responsible for the instruction sequence.
+    ///
+    /// # Example
+    ///
+    /// Read the STATUS register manually.
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00], vec![0x0C]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{instruction, Eeprom25aa02e48};
+    /// use embedded_hal::blocking::spi::{Transfer, Write};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let status: u8 = eeprom.with_transaction(|spi| {
+    ///     spi.write(&[instruction::RDSR])?;
+    ///     let mut buf: [u8; 1] = [0x00];
+    ///     spi.transfer(&mut buf)?;
+    ///     Ok::<u8, hal::MockError>(buf[0])
+    /// })?;
+    /// # assert_eq!(status, 0x0C);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`transaction`]: Self::transaction
+    pub fn with_transaction<R, E, F>(&mut self, f: F) -> Result<R, Error<E, PinError>>
+    where
+        F: FnOnce(&mut SPI) -> Result<R, E>,
+    {
+        with_chip_enable(&mut self.spi, &mut self.cs, self.cs_polarity, f)
+    }
+
+    /// Assert the chip select pin, starting a frame.
+    ///
+    /// This is for sequences built on [`spi_mut`] that need explicit control
+    /// of the frame, prefer [`with_transaction`] which always ends the frame.
+    ///
+    /// Every call must be balanced with a call to [`deselect`], driver
+    /// methods must not be called in between.
+    /// Leaving chip select asserted blocks the bus for other devices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00], vec![0x0C]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{instruction, Eeprom25aa02e48};
+    /// use embedded_hal::blocking::spi::{Transfer, Write};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.select()?;
+    /// eeprom.spi_mut().write(&[instruction::RDSR])?;
+    /// let mut buf: [u8; 1] = [0x00];
+    /// eeprom.spi_mut().transfer(&mut buf)?;
+    /// eeprom.deselect()?;
+    /// # assert_eq!(buf, [0x0C]);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), hal::MockError>(())
+    /// ```
+    ///
+    /// [`spi_mut`]: Self::spi_mut
+    /// [`with_transaction`]: Self::with_transaction
+    /// [`deselect`]: Self::deselect
+    pub fn select(&mut self) -> Result<(), PinError> {
+        chip_enable(&mut self.cs, self.cs_polarity)
+    }
+
+    /// Deassert the chip select pin, ending a frame started by [`select`].
+    ///
+    /// [`select`]: Self::select
+    pub fn deselect(&mut self) -> Result<(), PinError> {
+        chip_disable(&mut self.cs, self.cs_polarity)
+    }
+
+    /// Read `len` bytes starting from `address` in a single chip select
+    /// frame, calling `f` with each chunk as it is read.
+    ///
+    /// Chunks are up to [`STREAM_CHUNK`] bytes, in address order, only the
+    /// last chunk may be shorter.
+    /// This reads any amount of data without a buffer for all of it, for
+    /// example to feed a parser or a hash.
+    ///
+    /// This requires a chip select pin, an [`embedded-hal`] 1.0 SPI device
+    /// cannot keep the frame open between chunks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 32], vec![0x01; 32]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 8], vec![0x01; 8]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let mut sum: u32 = 0;
+    /// eeprom.read_stream(0x00, 40, |chunk| {
+    ///     sum += chunk.iter().map(|&byte| u32::from(byte)).sum::<u32>();
+    /// })?;
+    /// assert_eq!(sum, 40);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the read exceeds the end of the
+    /// memory array.
+    ///
+    /// [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+    pub fn read_stream<SpiError, F>(
+        &mut self,
+        address: u8,
+        len: usize,
+        mut f: F,
+    ) -> Result<(), Error<SpiError, PinError>>
+    where
+        SPI: blocking::spi::Transfer<u8, Error = SpiError>
+            + blocking::spi::Write<u8, Error = SpiError>,
+        F: FnMut(&[u8]),
+    {
+        check_args(address as usize + len <= CAPACITY, Error::OutOfBounds)?;
+        if len == 0 {
+            return Ok(());
+        }
+
+        with_chip_enable(&mut self.spi, &mut self.cs, self.cs_polarity, |spi| {
+            spi.write(&[instruction::READ, address])?;
+            let mut buf: [u8; STREAM_CHUNK] = [0; STREAM_CHUNK];
+            let mut remaining: usize = len;
+            while remaining != 0 {
+                let chunk: &mut [u8] = &mut buf[..remaining.min(STREAM_CHUNK)];
+                chunk.fill(0);
+                f(spi.transfer(chunk)?);
+                remaining -= chunk.len();
+            }
+            Ok(())
+        })
+    }
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI>
+{
+    /// Creates a new driver with an owned delay from a SPI bus, a chip
+    /// select digital I/O pin, and a delay.
+    ///
+    /// See [`DelayedEeprom`] for details.
+    pub const fn new_with_delay<D>(
+        spi: SPI,
+        cs: CS,
+        delay: D,
+    ) -> DelayedEeprom<SPI, CS, D, CAPACITY, PAGE, EUI>
+    where
+        SPI: WriteInterface<CS>,
+    {
+        DelayedEeprom::new(Self::new(spi, cs), delay)
+    }
+}
+
+impl<SPI, CS, SpiError, PinError, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    SPI: WriteInterface<CS, SpiError = SpiError, PinError = PinError>,
+    EUI: Identifier,
+{
+    /// Write `header` followed by `data` with the write enable latch set.
+    ///
+    /// The write latch is reset if the write fails.
+    fn write_latched(
+        &mut self,
+        header: &[u8],
+        data: &[u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.write_enable()?;
+        if let Some(verify) = self.verify_write_enable {
+            let mut latched: Result<bool, Error<SpiError, PinError>> = Ok(false);
+            verify(&mut self.spi, &mut self.cs, self.cs_polarity, &mut latched);
+            if !latched? {
+                return Err(Error::WriteEnableFailed);
+            }
+        }
+        let result = self
+            .spi
+            .write_frame(&mut self.cs, self.cs_polarity, header, data);
+        // write latch automatically resets on successful write
+        if result.is_err() {
+            self.write_disable()?;
+        }
+        result
+    }
+
+    /// Set the write enable latch.
+    ///
+    /// Write methods on this driver set the latch automatically, this is only
+    /// required for sequences built on top of the raw instructions.
+    ///
+    /// The EEPROM resets the write enable latch after every completed write
+    /// cycle, the latch must be set again before each independent write.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_enable()?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn write_enable(&mut self) -> Result<(), Error<SpiError, PinError>> {
+        self.spi
+            .write_frame(&mut self.cs, self.cs_polarity, &[instruction::WREN], &[])
+    }
+
+    /// Reset the write enable latch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WRDI]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_disable()?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn write_disable(&mut self) -> Result<(), Error<SpiError, PinError>> {
+        self.spi
+            .write_frame(&mut self.cs, self.cs_polarity, &[instruction::WRDI], &[])
+    }
+
+    /// Writes up to a page of data to the EEPROM.
+    ///
+    /// The write enable latch is set with a [`WREN`] instruction in a
+    /// separate chip select frame before the write.
+    /// The EEPROM resets the latch automatically when the write completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `data` - Data to write, must be less than or equal to the page size in length.
+    ///
+    /// # Example
+    ///
+    /// Write to the second page (page 1).
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write(vec![0x12; 16]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let data: [u8; 16] = [0x12; 16];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_page(0x10, &data)?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// The data is borrowed, a page can be written directly from a larger
+    /// buffer without copying.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x20]),
+    /// #   hal::spi::Transaction::write(vec![0x34; 16]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut image: [u8; 256] = [0x12; 256];
+    /// image[0x20..0x30].fill(0x34);
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_page(0x20, &image[0x20..0x30])?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the data length exceeds the page
+    /// size (16), or if the data length plus address exceeds the capacity.
+    /// Without the `checked-errors` feature the argument errors are debug
+    /// assertions.
+    ///
+    /// Returns [`Error::ProtectedRegion`] if the write overlaps the factory
+    /// programmed identifier, see [`protect_eui`].
+    ///
+    /// [`protect_eui`]: Self::protect_eui
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let data: [u8; 17] = [0x00; 17];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// # #[cfg(feature = "checked-errors")]
+    /// # {
+    /// let result = eeprom.write_page(0, &data);
+    /// assert!(matches!(result, Err(Error::OutOfBounds)));
+    /// # }
+    /// ```
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25x, Error};
+    ///
+    /// // 1 Kbit EEPROM with 16 byte pages
+    /// let mut eeprom: Eeprom25x<_, _, 128, 16> = Eeprom25x::new(spi, pin);
+    /// let data: [u8; 16] = [0x00; 16];
+    /// # #[cfg(feature = "checked-errors")]
+    /// # {
+    /// let result = eeprom.write_page(0x80, &data);
+    /// assert!(matches!(result, Err(Error::OutOfBounds)));
+    /// # }
+    /// ```
+    ///
+    /// Returns [`Error::Misaligned`] if the address is not page aligned.
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let data: [u8; 16] = [0x00; 16];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// # #[cfg(feature = "checked-errors")]
+    /// # {
+    /// let result = eeprom.write_page(1, &data);
+    /// assert!(matches!(result, Err(Error::Misaligned)));
+    /// # }
+    /// ```
+    ///
+    /// [`WREN`]: instruction::WREN
+    pub fn write_page(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        check_args(usize::from(address).is_multiple_of(PAGE), Error::Misaligned)?;
+        check_args(
+            data.len() <= PAGE && address as usize + data.len() <= CAPACITY,
+            Error::OutOfBounds,
+        )?;
+        self.page_write(address, data)
+    }
+
+    /// Write up to a page of data to the EEPROM without checking the
+    /// alignment and bounds of the arguments.
+    ///
+    /// This is [`write_page`] without the [`Error::Misaligned`] and
+    /// [`Error::OutOfBounds`] checks, for callers that have already validated
+    /// the arguments.
+    /// Invalid arguments do not cause undefined behavior, the EEPROM wraps
+    /// writes that cross a page boundary to the start of the page.
+    ///
+    /// The [`protect_eui`] guard still applies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write(vec![0x12, 0x34]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_page_unchecked(0x10, &[0x12, 0x34])?;
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`write_page`]: Self::write_page
+    /// [`protect_eui`]: Self::protect_eui
+    pub fn write_page_unchecked(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.page_write(address, data)
+    }
+
+    /// Write up to a page of data to the EEPROM and wait a fixed delay for
+    /// the write cycle to complete.
+    ///
+    /// This is [`write_page`] followed by a delay of the
+    /// [`write_cycle_time`], the STATUS register is not read.
+    /// There is no RDSR traffic on the bus and MISO is not used, which makes
+    /// this usable on buses where reads are unreliable, or where only the
+    /// write half of the bus is available.
+    ///
+    /// The delay must cover the worst case write cycle, which is usually
+    /// longer than the actual write cycle, every write takes the full write
+    /// cycle time.
+    /// [`write_page_and_wait`] delays for the same write cycle time before it
+    /// polls, at the default [`WRITE_CYCLE_TIME_US`] both take about the same
+    /// time and the difference is the RDSR traffic of the polls.
+    /// With a lowered write cycle time [`write_page_and_wait`] starts polling
+    /// sooner and returns when the write completes, which lowers the latency
+    /// of fast write cycles.
+    /// Endurance is not affected, the EEPROM performs the same write cycle
+    /// either way.
+    /// If the write cycle time is set lower than the actual write cycle the
+    /// next instruction is ignored by the EEPROM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write(vec![0x12, 0x34]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_page_blocking_fixed(0x10, &[0x12, 0x34], &mut delay)?;
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same as [`write_page`].
+    ///
+    /// [`write_page`]: Self::write_page
+    /// [`write_cycle_time`]: Self::write_cycle_time
+    /// [`write_page_and_wait`]: Self::write_page_and_wait
+    pub fn write_page_blocking_fixed<D: DelayUs<u32>>(
+        &mut self,
+        address: u8,
+        data: &[u8],
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.write_page(address, data)?;
+        delay.delay_us(self.write_cycle_time);
+        Ok(())
+    }
+
+    /// Write a page of data followed by a CRC-8 of the data in the last byte
+    /// of the page.
+    ///
+    /// `data` must be one byte shorter than the page, 15 bytes for the
+    /// 25AA02E48.
+    /// The CRC-8 is the SMBus CRC-8, with polynomial `0x07` and an initial
+    /// value of `0x00`.
+    ///
+    /// Use [`read_page_checked`] to read the page and verify the CRC-8.
+    ///
+    /// # Example
+    ///
+    /// Write a page, and read it back.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let mut page: Vec<u8> = (0x01..=0x0F).collect();
+    /// # page.push(0x41);
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x20]),
+    /// #   hal::spi::Transaction::write(page.clone()),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x20]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 16], page),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..4 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let data: [u8; 15] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_page_with_crc8(0x20, &data)?;
+    /// eeprom.wait_ready(&mut delay)?;
+    ///
+    /// let mut buf: [u8; 15] = [0; 15];
+    /// eeprom.read_page_checked(0x20, &mut buf)?;
+    /// assert_eq!(buf, data);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the data is not one byte shorter than
+    /// the page.
+    /// Without the `checked-errors` feature this is a debug assertion.
+    ///
+    /// See [`write_page`] for other errors.
+    ///
+    /// [`read_page_checked`]: Self::read_page_checked
+    /// [`write_page`]: Self::write_page
+    pub fn write_page_with_crc8(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        check_args(data.len() + 1 == PAGE, Error::OutOfBounds)?;
+        let mut page: [u8; PAGE] = [0; PAGE];
+        page[..data.len()].copy_from_slice(data);
+        page[data.len()] = crc::crc8(data);
+        self.write_page(address, &page)
+    }
+
+    /// Write data that does not cross a page boundary, without checking the
+    /// bounds of the arguments.
+    fn page_write(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SpiError, PinError>> {
+        self.check_protected(address, data.len())?;
+        if data.is_empty() {
+            Ok(())
+        } else {
+            let cmd: [u8; 2] = [instruction::WRITE, address];
+            self.write_latched(&cmd, data)
+        }
+    }
+
+    /// Write the STATUS register.
+    ///
+    /// Only the block protection bits are writable, all other bits in `value`
+    /// are ignored.
+    ///
+    /// Like other writes this starts a write cycle, a write enable latch is
+    /// set before the write.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x03]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRSR, 0x00]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let status = eeprom.read_status_register()?;
+    /// // WIP and WEL are read-only and not sent to the EEPROM
+    /// eeprom.write_status_register(status)?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn write_status_register(
+        &mut self,
+        value: StatusRegister,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        let cmd: [u8; 2] = [
+            instruction::WRSR,
+            u8::from(value) & StatusRegister::WRITABLE,
+        ];
+        self.write_latched(&cmd, &[])
+    }
+
+    /// Start writing a single byte to the EEPROM.
+    ///
+    /// This returns as soon as the write cycle has started, use
+    /// [`write_poll`] to check for completion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x42]),
+    /// #   hal::spi::Transaction::write(vec![0xAB]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x03]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..4 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_byte_start(0x42, 0xAB)?;
+    /// loop {
+    ///     match eeprom.write_poll() {
+    ///         Ok(()) => break,
+    ///         Err(nb::Error::WouldBlock) => {
+    ///             // do other work
+    ///         }
+    ///         Err(nb::Error::Other(e)) => return Err(e),
+    ///     }
+    /// }
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the address exceeds the capacity.
+    ///
+    /// Returns [`Error::ProtectedRegion`] if the write overlaps the factory
+    /// programmed identifier, see [`protect_eui`].
+    ///
+    /// [`write_poll`]: Self::write_poll
+    /// [`protect_eui`]: Self::protect_eui
+    pub fn write_byte_start(
+        &mut self,
+        address: u8,
+        data: u8,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        check_args(usize::from(address) < CAPACITY, Error::OutOfBounds)?;
+        self.page_write(address, &[data])
+    }
+}
+
+impl<SPI, CS, SpiError, PinError, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+    EUI: Identifier,
+{
+    /// Read from the EEPROM.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `buf` - Buffer to read data into.
+    ///   The size of the buffer determines the number of bytes read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 64], vec![0x00; 64]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut some_big_buf: [u8; 1024] = [0; 1024];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// // read 64 bytes starting at EEPROM address 0x00
+    /// eeprom.read(0x00, &mut some_big_buf[..64])?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// Reads longer than 255 bytes are split into multiple READ commands,
+    /// for SPI drivers that limit the length of a single transfer.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 255], vec![0x00; 255]),
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0xFF]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 1], vec![0x00; 1]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut buf: [u8; 256] = [0; 256];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.read(0x00, &mut buf)?;
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// Reading into an empty buffer returns immediately without using the bus.
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.read(0x00, &mut [])?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the buffer length plus address exceeds
+    /// the maximum address of `0xFF`.
+    /// Without the `checked-errors` feature this is a debug assertion.
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// # let pin = hal::pin::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut buf: [u8; 16] = [0; 16];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// # #[cfg(feature = "checked-errors")]
+    /// # {
+    /// let result = eeprom.read(0xF8, &mut buf);
+    /// assert!(matches!(result, Err(Error::OutOfBounds)));
+    /// # }
+    /// ```
+    pub fn read(&mut self, address: u8, buf: &mut [u8]) -> Result<(), Error<SpiError, PinError>> {
+        check_args(address as usize + buf.len() <= CAPACITY, Error::OutOfBounds)?;
+        self.read_unchecked(address, buf)
+    }
+
+    /// Read from the EEPROM, wrapping from the maximum address to `0x00`.
+    ///
+    /// Sequential reads on the EEPROM wrap to the start of the memory array,
+    /// this allows a read to cross the end of the memory array, for example
+    /// to read a ring buffer.
+    /// The buffer may be longer than the capacity, the memory array is read
+    /// repeatedly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0xFE]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 4], vec![0x01, 0x02, 0x03, 0x04]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut buf: [u8; 4] = [0; 4];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// // reads 0xFE, 0xFF, 0x00, 0x01
+    /// eeprom.read_wrapping(0xFE, &mut buf)?;
+    /// # assert_eq!(buf, [0x01, 0x02, 0x03, 0x04]);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the address exceeds the capacity.
+    #[doc(alias = "read_data_wrapping")]
+    pub fn read_wrapping(
+        &mut self,
+        address: u8,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        check_args(usize::from(address) < CAPACITY, Error::OutOfBounds)?;
+        let mut address: usize = address.into();
+        for chunk in buf.chunks_mut(MAX_TRANSFER) {
+            let cmd: [u8; 2] = [instruction::READ, address as u8];
+            self.spi
+                .read_frame(&mut self.cs, self.cs_polarity, &cmd, chunk)?;
+            address = (address + chunk.len()) % CAPACITY;
+        }
+        Ok(())
+    }
+
+    /// Read from the EEPROM without checking the bounds of the arguments.
+    ///
+    /// This is [`read`] without the [`Error::OutOfBounds`] check, for callers
+    /// that have already validated the arguments.
+    /// Invalid arguments do not cause undefined behavior, the EEPROM wraps
+    /// reads past the maximum address to `0x00`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 2], vec![0x12, 0x34]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut buf: [u8; 2] = [0; 2];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.read_unchecked(0x10, &mut buf)?;
+    /// # assert_eq!(buf, [0x12, 0x34]);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`read`]: Self::read
+    #[doc(alias = "read_data_unchecked")]
+    pub fn read_unchecked(
+        &mut self,
+        address: u8,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        let mut address: u8 = address;
+        for chunk in buf.chunks_mut(MAX_TRANSFER) {
+            let cmd: [u8; 2] = [instruction::READ, address];
+            self.spi
+                .read_frame(&mut self.cs, self.cs_polarity, &cmd, chunk)?;
+            address = address.wrapping_add(chunk.len() as u8);
+        }
+        Ok(())
+    }
+
+    /// Read up to 16 bytes from the EEPROM with a single SPI transfer.
+    ///
+    /// [`read`] writes the READ instruction and reads the data with two
+    /// separate bus operations in one chip select frame.
+    /// This copies the instruction into an 18 byte buffer on the stack and
+    /// performs one transfer over the instruction and data, which reduces the
+    /// per-call overhead of SPI drivers at the cost of a copy.
+    /// For reads of more than a few bytes [`read`] is usually faster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(
+    /// #       vec![instruction::READ, 0x10, 0x00, 0x00],
+    /// #       vec![0x00, 0x00, 0x12, 0x34],
+    /// #   ),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut buf: [u8; 2] = [0; 2];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.read_small(0x10, &mut buf)?;
+    /// # assert_eq!(buf, [0x12, 0x34]);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the buffer is longer than 16 bytes,
+    /// or if the buffer length plus address exceeds the capacity.
+    ///
+    /// [`read`]: Self::read
+    pub fn read_small(
+        &mut self,
+        address: u8,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        const CMD_LEN: usize = 2;
+        let mut frame: [u8; CMD_LEN + PAGE_SIZE as usize] = [0; CMD_LEN + PAGE_SIZE as usize];
+        check_args(address as usize + buf.len() <= CAPACITY, Error::OutOfBounds)?;
+        if buf.len() > frame.len() - CMD_LEN {
+            Err(Error::OutOfBounds)
+        } else if buf.is_empty() {
+            Ok(())
+        } else {
+            let frame: &mut [u8] = &mut frame[..CMD_LEN + buf.len()];
+            frame[0] = instruction::READ;
+            frame[1] = address;
+            self.spi
+                .transfer_frame(&mut self.cs, self.cs_polarity, frame)?;
+            buf.copy_from_slice(&frame[CMD_LEN..]);
+            Ok(())
+        }
+    }
+
+    /// Read a single byte from the EEPROM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x42]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00], vec![0xAB]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let byte: u8 = eeprom.read_byte(0x42)?;
+    /// # assert_eq!(byte, 0xAB);
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn read_byte(&mut self, address: u8) -> Result<u8, Error<SpiError, PinError>> {
+        let mut buf: [u8; 1] = [0];
+        self.read(address, &mut buf)?;
+        Ok(buf[0])
+    }
+
+    /// Read a fixed number of bytes from the EEPROM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x20]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 4], vec![0x78, 0x56, 0x34, 0x12]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let counter: u32 = u32::from_le_bytes(eeprom.read_array(0x20)?);
+    /// # assert_eq!(counter, 0x12345678);
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `N` plus the address exceeds the
+    /// capacity.
+    pub fn read_array<const N: usize>(
+        &mut self,
+        address: u8,
+    ) -> Result<[u8; N], Error<SpiError, PinError>> {
+        let mut buf: [u8; N] = [0; N];
+        self.read(address, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Read a plain old data value from the EEPROM.
+    ///
+    /// The value is read with [`read`] into a zeroed `T`, the bytes are in
+    /// the memory layout of `T`.
+    ///
+    /// This method is only available with the `bytemuck` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 4], vec![0x34, 0x12, 0x78, 0x56]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let config: [u16; 2] = eeprom.read_pod(0x10)?;
+    /// assert_eq!(config, [u16::from_le(0x1234), u16::from_le(0x5678)]);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the size of `T` plus the address
+    /// exceeds the capacity.
+    ///
+    /// [`read`]: Self::read
+    #[cfg(feature = "bytemuck")]
+    pub fn read_pod<T: bytemuck::Pod>(
+        &mut self,
+        address: u8,
+    ) -> Result<T, Error<SpiError, PinError>> {
+        let mut value: T = T::zeroed();
+        self.read(address, bytemuck::bytes_of_mut(&mut value))?;
+        Ok(value)
+    }
+
+    /// Read the entire memory array, starting at address `0x00`.
+    ///
+    /// This is [`read`] of the whole array, which splits reads longer than
+    /// 255 bytes into multiple READ commands for SPI drivers with a 255 byte
+    /// transfer limit.
+    /// A 256 byte array is read with two READ commands, of 255 bytes and 1
+    /// byte.
+    /// Use [`read_stream`] to read the array in a single READ command.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 255], vec![0xFF; 255]),
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0xFF]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 1], vec![0xFF; 1]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let image: [u8; 256] = eeprom.read_all()?;
+    /// # assert_eq!(image, [0xFF; 256]);
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`read`]: Self::read
+    /// [`read_stream`]: Self::read_stream
+    pub fn read_all(&mut self) -> Result<[u8; CAPACITY], Error<SpiError, PinError>> {
+        let mut buf: [u8; CAPACITY] = [0; CAPACITY];
+        self.read(0x00, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Read a page written with [`write_page_with_crc8`] and verify the CRC-8.
+    ///
+    /// `buf` must be one byte shorter than the page, 15 bytes for the
+    /// 25AA02E48.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 16], vec![0xFF; 16]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut buf: [u8; 15] = [0; 15];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// // erased pages do not have a valid CRC-8
+    /// let result = eeprom.read_page_checked(0x00, &mut buf);
+    /// assert!(matches!(result, Err(Error::CrcMismatch)));
+    /// # eeprom.free().0.done();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CrcMismatch`] if the CRC-8 does not match the data,
+    /// `buf` contains the data read regardless.
+    ///
+    /// Returns [`Error::Misaligned`] if the address is not page aligned.
+    ///
+    /// Returns [`Error::OutOfBounds`] if the buffer is not one byte shorter
+    /// than the page, or if the page exceeds the capacity.
+    ///
+    /// Without the `checked-errors` feature the argument errors are debug
+    /// assertions.
+    ///
+    /// [`write_page_with_crc8`]: Self::write_page_with_crc8
+    pub fn read_page_checked(
+        &mut self,
+        address: u8,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SpiError, PinError>> {
+        check_args(buf.len() + 1 == PAGE, Error::OutOfBounds)?;
+        check_args(usize::from(address).is_multiple_of(PAGE), Error::Misaligned)?;
+        let mut page: [u8; PAGE] = [0; PAGE];
+        self.read(address, &mut page)?;
+        let (data, crc) = page.split_at(buf.len());
+        buf.copy_from_slice(data);
+        if crc::crc8(data) == crc[0] {
+            Ok(())
+        } else {
+            Err(Error::CrcMismatch)
+        }
+    }
+
+    /// Compare a region of memory starting at `address` to `expected`.
+    ///
+    /// Returns `None` if the memory matches, or the address of the first
+    /// mismatched byte.
+    ///
+    /// The region is read in chunks of 16 bytes, a mismatch stops the
+    /// comparison early.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 3], vec![0x12, 0x34, 0x00]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let mismatch: Option<u8> = eeprom.compare_region(0x10, &[0x12, 0x34, 0x56])?;
+    /// assert_eq!(mismatch, Some(0x12));
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the length of `expected` plus address
+    /// exceeds the capacity.
+    pub fn compare_region(
+        &mut self,
+        address: u8,
+        expected: &[u8],
+    ) -> Result<Option<u8>, Error<SpiError, PinError>> {
+        check_args(
+            usize::from(address) + expected.len() <= CAPACITY,
+            Error::OutOfBounds,
+        )?;
+
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        let mut chunk_address: u8 = address;
+        for chunk in expected.chunks(buf.len()) {
+            let readback: &mut [u8] = &mut buf[..chunk.len()];
+            self.read(chunk_address, readback)?;
+            if let Some(offset) = chunk.iter().zip(readback.iter()).position(|(a, b)| a != b) {
+                return Ok(Some(chunk_address.wrapping_add(offset as u8)));
+            }
+            chunk_address = chunk_address.wrapping_add(chunk.len() as u8);
+        }
+        Ok(None)
+    }
+
+    /// Compute the CRC-32 (IEEE 802.3) of a range of memory.
+    ///
+    /// This is the same CRC-32 used by Ethernet, zlib, and PNG.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 9], b"123456789".to_vec()),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let crc: u32 = eeprom.crc32(0x00..=0x08)?;
+    /// # assert_eq!(crc, 0xCBF43926);
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the range exceeds the capacity.
+    pub fn crc32(&mut self, range: RangeInclusive<u8>) -> Result<u32, Error<SpiError, PinError>> {
+        let mut crc: u32 = crc::INIT;
+        self.read_chunks(range, |chunk| {
+            crc = crc::update(crc, chunk);
+            true
+        })?;
+        Ok(crc::finish(crc))
+    }
+
+    /// Returns `true` if every byte in a range of memory is erased (`0xFF`).
+    ///
+    /// The range is read in 16 byte chunks from the start of the range,
+    /// reading stops at the first chunk containing a byte that is not erased.
+    ///
+    /// This is not the free function [`crate::is_blank`], which checks an
+    /// EUI-48 for all `0x00` or all `0xFF`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 16], vec![0xFF; 16]),
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 16], vec![0x00; 16]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..2 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// assert!(eeprom.is_blank(0x00..=0x0F)?);
+    /// // the first chunk is not erased, the second chunk is not read
+    /// assert!(!eeprom.is_blank(0x10..=0x2F)?);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the range exceeds the capacity.
+    #[doc(alias = "is_erased")]
+    pub fn is_blank(
+        &mut self,
+        range: RangeInclusive<u8>,
+    ) -> Result<bool, Error<SpiError, PinError>> {
+        let mut erased: bool = true;
+        self.read_chunks(range, |chunk| {
+            erased = chunk.iter().all(|&byte| byte == 0xFF);
+            erased
+        })?;
+        Ok(erased)
+    }
+
+    /// Read a range of memory in 16 byte chunks from the start of the range,
+    /// passing each chunk to `f` until `f` returns `false`.
+    ///
+    /// Returns [`Error::OutOfBounds`] if the range exceeds the capacity.
+    fn read_chunks<F>(
+        &mut self,
+        range: RangeInclusive<u8>,
+        mut f: F,
+    ) -> Result<(), Error<SpiError, PinError>>
+    where
+        F: FnMut(&[u8]) -> bool,
+    {
+        let (start, end) = range.into_inner();
+        check_args(usize::from(end) < CAPACITY, Error::OutOfBounds)?;
+
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        let mut address: usize = start.into();
+        while address <= usize::from(end) {
+            let len: usize = (usize::from(end) + 1 - address).min(buf.len());
+            let chunk: &mut [u8] = &mut buf[..len];
+            self.read(address as u8, chunk)?;
+            if !f(chunk) {
+                break;
+            }
+            address += len;
+        }
+        Ok(())
+    }
+
+    /// Perform a raw SPI transfer in a single chip select frame.
+    ///
+    /// `bytes` is sent to the EEPROM and replaced with the bytes received.
+    ///
+    /// This is an escape hatch for instructions the driver does not
+    /// implement.
+    /// It bypasses all driver logic, the write enable latch is not set and
+    /// the write cycle is not waited for, the caller is responsible for the
+    /// instruction sequence.
+    ///
+    /// # Example
+    ///
+    /// Read the STATUS register manually.
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x0C]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{instruction, Eeprom25aa02e48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let mut bytes: [u8; 2] = [instruction::RDSR, 0x00];
+    /// eeprom.transaction(&mut bytes)?;
+    /// # assert_eq!(bytes[1], 0x0C);
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn transaction(&mut self, bytes: &mut [u8]) -> Result<(), Error<SpiError, PinError>> {
+        self.spi
+            .transfer_frame(&mut self.cs, self.cs_polarity, bytes)
+    }
+
+    /// Read the STATUS register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x02]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, StatusRegister};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let status: StatusRegister = eeprom.read_status_register()?;
+    /// # assert_eq!(u8::from(status), 0x02);
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn read_status_register(&mut self) -> Result<StatusRegister, Error<SpiError, PinError>> {
+        let mut buf: [u8; 2] = [instruction::RDSR, 0];
+        self.spi
+            .transfer_frame(&mut self.cs, self.cs_polarity, &mut buf)?;
+        Ok(StatusRegister::from(buf[1]))
+    }
+
+    /// Set the write enable latch and read it back from the STATUS register.
+    ///
+    /// This is [`write_enable`] followed by [`read_status_register`], use this
+    /// before a raw write to detect a WREN instruction that did not reach
+    /// the EEPROM, for example due to a bad connection.
+    /// The write fails silently without the latch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WriteEnableFailed`] if the write enable latch is not
+    /// set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x02]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..4 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_enable_verified()?;
+    ///
+    /// // WREN did not reach the EEPROM
+    /// assert_eq!(eeprom.write_enable_verified(), Err(Error::WriteEnableFailed));
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`write_enable`]: Self::write_enable
+    /// [`read_status_register`]: Self::read_status_register
+    #[doc(alias = "verify_wren")]
+    pub fn write_enable_verified(&mut self) -> Result<(), Error<SpiError, PinError>> {
+        self.write_enable()?;
+        if self.read_status_register()?.write_enable_latch() {
+            Ok(())
+        } else {
+            Err(Error::WriteEnableFailed)
+        }
+    }
+
+    /// Enable or disable verification of the write enable latch before every
+    /// write.
+    ///
+    /// When enabled the driver reads the STATUS register after each WREN
+    /// instruction, and the write methods return
+    /// [`Error::WriteEnableFailed`] without writing if the latch is not set.
+    /// This costs one STATUS register read per write.
+    ///
+    /// Verification is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x02]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..5 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.verify_write_enable(true);
+    /// eeprom.write_page(0x10, &[0x12, 0x34])?;
+    ///
+    /// // WREN did not reach the EEPROM, nothing is written
+    /// let result = eeprom.write_page(0x10, &[0x12, 0x34]);
+    /// assert_eq!(result, Err(Error::WriteEnableFailed));
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// An error reading the STATUS register is returned as the SPI or pin
+    /// error, and nothing is written.
+    ///
+    /// ```
+    /// # use embedded_hal_mock as hal;
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..2 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// use eeprom25aa02e48::{hal::blocking::spi, Eeprom25aa02e48, Error};
+    ///
+    /// # struct FailingReads;
+    /// # impl spi::Write<u8> for FailingReads {
+    /// #     type Error = ();
+    /// #     fn write(&mut self, _: &[u8]) -> Result<(), ()> { Ok(()) }
+    /// # }
+    /// # impl spi::Transfer<u8> for FailingReads {
+    /// #     type Error = ();
+    /// #     fn transfer<'w>(&mut self, _: &'w mut [u8]) -> Result<&'w [u8], ()> { Err(()) }
+    /// # }
+    /// // SPI bus that fails every read
+    /// let mut eeprom = Eeprom25aa02e48::new(FailingReads, pin);
+    /// eeprom.verify_write_enable(true);
+    /// let result = eeprom.write_page(0x10, &[0x12, 0x34]);
+    /// assert_eq!(result, Err(Error::Spi(())));
+    /// # eeprom.free().1.done();
+    /// ```
+    pub fn verify_write_enable(&mut self, enabled: bool) {
+        self.verify_write_enable = if enabled {
+            Some(Self::write_enable_latched)
+        } else {
+            None
+        };
+    }
+
+    /// Read the write enable latch from the STATUS register in a single chip
+    /// select frame.
+    fn write_enable_latched(
+        spi: &mut SPI,
+        cs: &mut CS,
+        polarity: CsPolarity,
+        latched: &mut dyn private::LatchResult<SPI, CS>,
+    ) {
+        let mut buf: [u8; 2] = [instruction::RDSR, 0];
+        latched.set(
+            spi.transfer_frame(cs, polarity, &mut buf)
+                .map(|()| StatusRegister::from(buf[1]).write_enable_latch()),
+        );
+    }
+
+    /// Set the array block write protection.
+    ///
+    /// This reads the STATUS register, updates the block protection bits, and
+    /// writes it back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRSR, 0x04]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{BlockProtect, Eeprom25aa02e48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// // protect the upper quarter, which contains the EUI-48
+    /// eeprom.set_block_protection(BlockProtect::Upper64)?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn set_block_protection(
+        &mut self,
+        bp: BlockProtect,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        let status: StatusRegister = self.read_status_register()?;
+        self.write_status_register(status.with_block_protect(bp))
+    }
+
+    /// Returns `true` if a write cycle is in progress.
+    ///
+    /// This reads the STATUS register once, use [`wait_ready`] to block until
+    /// the write cycle completes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x01]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let busy: bool = eeprom.busy()?;
+    /// # assert!(busy);
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`wait_ready`]: Self::wait_ready
+    #[doc(alias = "is_write_in_progress")]
+    pub fn busy(&mut self) -> Result<bool, Error<SpiError, PinError>> {
+        Ok(self.read_status_register()?.write_in_progress())
+    }
+
+    /// Write a single byte to the EEPROM and wait for the write cycle to
+    /// complete.
+    ///
+    /// This is [`write_byte_start`] followed by [`wait_ready`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x42]),
+    /// #   hal::spi::Transaction::write(vec![0xAB]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..3 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_byte_and_wait(0x42, 0xAB, &mut delay)?;
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`write_byte_start`]: Self::write_byte_start
+    /// [`wait_ready`]: Self::wait_ready
+    pub fn write_byte_and_wait<D: DelayUs<u32>>(
+        &mut self,
+        address: u8,
+        data: u8,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.write_byte_start(address, data)?;
+        self.wait_ready(delay)
+    }
+
+    /// Write up to a page of data to the EEPROM and wait for the write cycle
+    /// to complete.
+    ///
+    /// This is [`write_page`] followed by [`wait_ready`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x20]),
+    /// #   hal::spi::Transaction::write(vec![0x12, 0x34, 0x56, 0x78]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x03]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..4 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_page_and_wait(0x20, &[0x12, 0x34, 0x56, 0x78], &mut delay)?;
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// [`write_page`]: Self::write_page
+    /// [`wait_ready`]: Self::wait_ready
+    pub fn write_page_and_wait<D: DelayUs<u32>>(
+        &mut self,
+        address: u8,
+        data: &[u8],
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.write_page(address, data)?;
+        self.wait_ready(delay)
+    }
+
+    /// Read a byte, modify it, and write it back.
+    ///
+    /// This waits for the write cycle to complete, and returns the value
+    /// written.
+    ///
+    /// # Example
+    ///
+    /// Increment a counter.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x42]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00], vec![0x07]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x42]),
+    /// #   hal::spi::Transaction::write(vec![0x08]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..4 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let value: u8 = eeprom.modify_byte(0x42, |b| b.wrapping_add(1), &mut delay)?;
+    /// assert_eq!(value, 0x08);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn modify_byte<F, D>(
+        &mut self,
+        address: u8,
+        f: F,
+        delay: &mut D,
+    ) -> Result<u8, Error<SpiError, PinError>>
+    where
+        F: FnOnce(u8) -> u8,
+        D: DelayUs<u32>,
+    {
+        let value: u8 = f(self.read_byte(address)?);
+        self.write_byte_and_wait(address, value, delay)?;
+        Ok(value)
+    }
+
+    /// Check if a write cycle has completed.
+    ///
+    /// Returns [`nb::Error::WouldBlock`] while the write cycle is in progress.
+    pub fn write_poll(&mut self) -> nb::Result<(), Error<SpiError, PinError>> {
+        if self.busy()? {
+            Err(nb::Error::WouldBlock)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wait for a write cycle to complete.
+    ///
+    /// This delays for the [`write_cycle_time`], then polls the write in
+    /// progress bit of the STATUS register until it is cleared, delaying
+    /// between each poll.
+    ///
+    /// [`write_cycle_time`]: Self::write_cycle_time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x03]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.wait_ready(&mut delay)?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn wait_ready<D: DelayUs<u32>>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        delay.delay_us(self.write_cycle_time);
+        while self.busy()? {
+            delay.delay_us(POLL_INTERVAL_US);
+        }
+        Ok(())
+    }
+
+    /// Wait for a write cycle to complete, reading the STATUS register at
+    /// most `max_polls` times.
+    ///
+    /// Like [`wait_ready`] this delays for the [`write_cycle_time`] before the
+    /// first read.
+    ///
+    /// [`wait_ready`]: Self::wait_ready
+    /// [`write_cycle_time`]: Self::write_cycle_time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x03]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// // polls start after the write cycle time and are 100 µs apart
+    /// eeprom.wait_ready_timeout(&mut delay, 10)?;
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if the write cycle is still in progress
+    /// after `max_polls` reads.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x03]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x03]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let result = eeprom.wait_ready_timeout(&mut delay, 2);
+    /// assert_eq!(result, Err(Error::Timeout));
+    /// ```
+    pub fn wait_ready_timeout<D: DelayUs<u32>>(
+        &mut self,
+        delay: &mut D,
+        max_polls: u32,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        delay.delay_us(self.write_cycle_time);
+        for _ in 0..max_polls {
+            if !self.busy()? {
+                return Ok(());
+            }
+            delay.delay_us(POLL_INTERVAL_US);
+        }
+        Err(Error::Timeout)
+    }
+
+    /// Write data of any length to the EEPROM.
+    ///
+    /// The data is split into page writes on page boundaries, the first and
+    /// last page writes may be partial pages.
+    /// After each page write this waits for the write cycle to complete with
+    /// [`wait_ready`].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `data` - Data to write.
+    /// * `delay` - Delay used between STATUS register polls.
+    ///
+    /// # Example
+    ///
+    /// Write 40 bytes starting at address 0x08, this is split into page writes
+    /// of 8, 16, and 16 bytes.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let mut spi_transactions = Vec::new();
+    /// # let mut pin_transactions = Vec::new();
+    /// # for (address, len) in [(0x08, 8), (0x10, 16), (0x20, 16)] {
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![instruction::WREN]));
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![instruction::WRITE, address]));
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![0x5A; len]));
+    /// #   spi_transactions.push(hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]));
+    /// #   for _ in 0..3 {
+    /// #     pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #     pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// #   }
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&spi_transactions);
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let data: [u8; 40] = [0x5A; 40];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_data(0x08, &data, &mut delay)?;
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the data length plus address exceeds
+    /// the maximum address of `0xFF`.
+    ///
+    /// Returns [`Error::ProtectedRegion`] if the write overlaps the factory
+    /// programmed identifier, see [`protect_eui`].
+    ///
+    /// [`wait_ready`]: Self::wait_ready
+    /// [`protect_eui`]: Self::protect_eui
+    pub fn write_data<D: DelayUs<u32>>(
+        &mut self,
+        address: u8,
+        data: &[u8],
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        check_args(
+            address as usize + data.len() <= CAPACITY,
+            Error::OutOfBounds,
+        )?;
+        self.check_protected(address, data.len())?;
+
+        self.write_paged(address, data.len(), delay, |offset, len| {
+            &data[offset..offset + len]
+        })?;
+        Ok(())
+    }
+
+    /// Write a plain old data value to the EEPROM, waiting for each write
+    /// cycle to complete.
+    ///
+    /// This is [`write_data`] with the bytes of `value` in the memory layout
+    /// of `T`, read the value back with [`read_pod`].
+    ///
+    /// This method is only available with the `bytemuck` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write(bytemuck::bytes_of(&[0x1234_u16, 0x5678]).to_vec()),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..3 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let config: [u16; 2] = [0x1234, 0x5678];
+    /// eeprom.write_pod(0x10, &config, &mut delay)?;
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same as [`write_data`].
+    ///
+    /// [`write_data`]: Self::write_data
+    /// [`read_pod`]: Self::read_pod
+    #[cfg(feature = "bytemuck")]
+    pub fn write_pod<T: bytemuck::Pod, D: DelayUs<u32>>(
+        &mut self,
+        address: u8,
+        value: &T,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.write_data(address, bytemuck::bytes_of(value), delay)
+    }
+
+    /// Write whole pages of data to the EEPROM, waiting for each write cycle
+    /// to complete.
+    ///
+    /// This is the aligned case of [`write_data`], `address` must be page
+    /// aligned and the data length must be a multiple of the page size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let mut spi_transactions = Vec::new();
+    /// # let mut pin_transactions = Vec::new();
+    /// # for address in [0x20, 0x30] {
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![instruction::WREN]));
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![instruction::WRITE, address]));
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![0x5A; 16]));
+    /// #   spi_transactions.push(hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]));
+    /// #   for _ in 0..3 {
+    /// #     pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #     pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// #   }
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&spi_transactions);
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let data: [u8; 32] = [0x5A; 32];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// eeprom.write_pages(0x20, &data, &mut delay)?;
+    ///
+    /// # #[cfg(feature = "checked-errors")]
+    /// # {
+    /// let result = eeprom.write_pages(0x20, &data[..20], &mut delay);
+    /// assert!(matches!(result, Err(Error::Misaligned)));
+    /// # }
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Misaligned`] if the address is not page aligned, or
+    /// if the data length is not a multiple of the page size.
+    /// Without the `checked-errors` feature this is a debug assertion.
+    ///
+    /// Returns [`Error::OutOfBounds`] if the data length plus address exceeds
+    /// the capacity.
+    ///
+    /// Returns [`Error::ProtectedRegion`] if the write overlaps the factory
+    /// programmed identifier, see [`protect_eui`].
+    ///
+    /// [`write_data`]: Self::write_data
+    /// [`protect_eui`]: Self::protect_eui
+    pub fn write_pages<D: DelayUs<u32>>(
+        &mut self,
+        address: u8,
+        data: &[u8],
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        check_args(
+            usize::from(address).is_multiple_of(PAGE) && data.len().is_multiple_of(PAGE),
+            Error::Misaligned,
+        )?;
+        check_args(
+            address as usize + data.len() <= CAPACITY,
+            Error::OutOfBounds,
+        )?;
+        self.check_protected(address, data.len())?;
+
+        self.write_paged(address, data.len(), delay, |offset, len| {
+            &data[offset..offset + len]
+        })?;
+        Ok(())
+    }
+
+    /// Write `len` bytes starting at `address` with a page write for each
+    /// page, waiting for each write cycle to complete, without checking the
+    /// arguments.
+    ///
+    /// `page` returns the data for a page write from the offset relative to
+    /// `address` and the length of the page write.
+    ///
+    /// Returns the number of pages written.
+    fn write_paged<'a, D, F>(
+        &mut self,
+        address: u8,
+        len: usize,
+        delay: &mut D,
+        mut page: F,
+    ) -> Result<usize, Error<SpiError, PinError>>
+    where
+        D: DelayUs<u32>,
+        F: FnMut(usize, usize) -> &'a [u8],
+    {
+        let mut pages: usize = 0;
+        for write in WritePlan::<PAGE>::new(address, len) {
+            self.page_write(write.address, page(write.data.start, write.data.len()))?;
+            self.wait_ready(delay)?;
+            pages += 1;
+        }
+        Ok(pages)
+    }
+
+    /// Write data of any length to the EEPROM and read it back to verify it.
+    ///
+    /// This is [`write_data`] followed by a [`compare_region`] of the written
+    /// region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::transfer(vec![0; 2], vec![0x12, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..4 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let result = eeprom.write_data_verified(0x10, &[0x12, 0x34], &mut delay);
+    /// assert!(matches!(result, Err(Error::VerifyFailed { address: 0x11 })));
+    /// # eeprom.free().0.done();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::VerifyFailed`] with the address of the first mismatched
+    /// byte if the data read back does not match.
+    ///
+    /// [`write_data`]: Self::write_data
+    /// [`compare_region`]: Self::compare_region
+    pub fn write_data_verified<D: DelayUs<u32>>(
+        &mut self,
+        address: u8,
+        data: &[u8],
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        self.write_data(address, data, delay)?;
+        match self.compare_region(address, data)? {
+            Some(address) => Err(Error::VerifyFailed { address }),
+            None => Ok(()),
+        }
+    }
+
+    /// Write data of any length to the EEPROM, skipping pages that already
+    /// contain the data.
+    ///
+    /// Each page of the region is compared with [`compare_region`] and only
+    /// written if it differs, which saves write endurance for data that
+    /// rarely changes, such as configuration written on every boot.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Example
+    ///
+    /// Only the second page differs.
     ///
-    /// The chip select pin must be high before being passed to this function.
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x08]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 8], vec![0x5A; 8]),
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 4], vec![0xFF; 4]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write(vec![0x5A; 4]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..5 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
     ///
-    /// # Example
+    /// let data: [u8; 12] = [0x5A; 12];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let written: usize = eeprom.write_data_if_changed(0x08, &data, &mut delay)?;
+    /// assert_eq!(written, 4);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
     ///
-    /// The `spi` and `pin` variables in this example will be provided by your
-    /// device-specific hal crate.
+    /// Nothing is written if the data is unchanged.
     ///
     /// ```
+    /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock as hal;
-    /// # let spi = hal::spi::Mock::new(&[]);
-    /// # let mut pin = hal::pin::Mock::new(&[
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 2], vec![0x12, 0x34]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
     /// #    hal::pin::Transaction::set(hal::pin::State::High),
     /// # ]);
+    /// # let mut delay = hal::delay::MockNoop::new();
     /// use eeprom25aa02e48::Eeprom25aa02e48;
-    /// use embedded_hal::digital::v2::OutputPin;
     ///
-    /// pin.set_high()?;
     /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
-    /// # Ok::<(), hal::MockError>(())
+    /// let written: usize = eeprom.write_data_if_changed(0x00, &[0x12, 0x34], &mut delay)?;
+    /// assert_eq!(written, 0);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
     /// ```
-    pub fn new(spi: SPI, cs: CS) -> Self {
-        Eeprom25aa02e48 { spi, cs }
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the data length plus address exceeds
+    /// the capacity.
+    ///
+    /// Returns [`Error::ProtectedRegion`] if the write overlaps the factory
+    /// programmed identifier, see [`protect_eui`].
+    ///
+    /// [`compare_region`]: Self::compare_region
+    /// [`protect_eui`]: Self::protect_eui
+    pub fn write_data_if_changed<D: DelayUs<u32>>(
+        &mut self,
+        address: u8,
+        data: &[u8],
+        delay: &mut D,
+    ) -> Result<usize, Error<SpiError, PinError>> {
+        check_args(
+            address as usize + data.len() <= CAPACITY,
+            Error::OutOfBounds,
+        )?;
+        self.check_protected(address, data.len())?;
+
+        let mut written: usize = 0;
+        for write in WritePlan::<PAGE>::new(address, data.len()) {
+            let page: &[u8] = &data[write.data];
+            if self.compare_region(write.address, page)?.is_some() {
+                self.page_write(write.address, page)?;
+                self.wait_ready(delay)?;
+                written += page.len();
+            }
+        }
+        Ok(written)
     }
 
-    /// Free the SPI bus and CS pin from the W5500.
+    /// Write scattered bytes, waiting for each write cycle to complete.
+    ///
+    /// Items are `(address, data)` pairs.
+    /// Consecutive items with consecutive addresses in the same page are
+    /// buffered internally and combined into a single page write, all other
+    /// items start a new write.
+    /// Items are written in order, sort the items by address to combine as
+    /// many as possible.
+    ///
+    /// Returns the number of write cycles.
     ///
     /// # Example
     ///
     /// ```
+    /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock as hal;
-    /// # let spi = hal::spi::Mock::new(&[]);
-    /// # let pin = hal::pin::Mock::new(&[]);
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write(vec![0x01, 0x02, 0x03]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x40]),
+    /// #   hal::spi::Transaction::write(vec![0xAB]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..6 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
     /// use eeprom25aa02e48::Eeprom25aa02e48;
     ///
     /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
-    /// let (spi, pin) = eeprom.free();
+    /// let items = [(0x10, 0x01), (0x11, 0x02), (0x12, 0x03), (0x40, 0xAB)];
+    /// let cycles: usize = eeprom.write_bytes(items, &mut delay)?;
+    /// assert_eq!(cycles, 2);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
     /// ```
-    pub fn free(self) -> (SPI, CS) {
-        (self.spi, self.cs)
-    }
-
-    /// Context manager to ensure CS is always set high after an operation.
-    #[inline(always)]
-    fn with_chip_enable<T, E, F>(&mut self, mut f: F) -> Result<T, E>
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] for an address past the end of the
+    /// memory array, and [`Error::ProtectedRegion`] for a write to the factory
+    /// programmed identifier, see [`protect_eui`].
+    /// Without the `checked-errors` feature the out of bounds check is a debug
+    /// assertion.
+    ///
+    /// Items are checked as they are taken from the iterator, the run buffered
+    /// before the invalid item is written before the error is returned.
+    /// Every item before the invalid item has been written, the invalid item
+    /// and the items after it have not.
+    /// After a bus error the run being written may be partially written.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write(vec![0x01, 0x02]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..3 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::{Eeprom25x, Error};
+    ///
+    /// // 128 byte EEPROM
+    /// let mut eeprom: Eeprom25x<_, _, 128, 16> = Eeprom25x::new(spi, pin);
+    /// # #[cfg(feature = "checked-errors")]
+    /// # {
+    /// // 0x10 and 0x11 are written, 0x80 and 0x12 are not
+    /// let items = [(0x10, 0x01), (0x11, 0x02), (0x80, 0x03), (0x12, 0x04)];
+    /// let result = eeprom.write_bytes(items, &mut delay);
+    /// assert_eq!(result, Err(Error::OutOfBounds));
+    /// # eeprom.free().0.done();
+    /// # }
+    /// ```
+    ///
+    /// [`protect_eui`]: Self::protect_eui
+    pub fn write_bytes<I, D>(
+        &mut self,
+        items: I,
+        delay: &mut D,
+    ) -> Result<usize, Error<SpiError, PinError>>
     where
-        F: FnMut(&mut SPI) -> Result<T, E>,
-        E: core::convert::From<Error<SpiError, PinError>>,
+        I: IntoIterator<Item = (u8, u8)>,
+        D: DelayUs<u32>,
     {
-        self.cs.set_low().map_err(Error::Pin)?;
-        let result = f(&mut self.spi);
-        self.cs.set_high().map_err(Error::Pin)?;
-        result
+        let mut buf: [u8; PAGE] = [0; PAGE];
+        let mut start: u8 = 0;
+        let mut len: usize = 0;
+        let mut cycles: usize = 0;
+        for (address, data) in items {
+            let valid: Result<(), Error<SpiError, PinError>> =
+                check_args(usize::from(address) < CAPACITY, Error::OutOfBounds)
+                    .and_then(|()| self.check_protected(address, 1));
+            if let Err(e) = valid {
+                self.write_run(start, &buf[..len], delay)?;
+                return Err(e);
+            }
+            let next: usize = usize::from(start) + len;
+            if len == 0 || usize::from(address) != next || next.is_multiple_of(PAGE) {
+                cycles += self.write_run(start, &buf[..len], delay)?;
+                start = address;
+                len = 0;
+            }
+            buf[len] = data;
+            len += 1;
+        }
+        cycles += self.write_run(start, &buf[..len], delay)?;
+        Ok(cycles)
     }
 
-    /// Context manager to ensure the write latch is always disabled after an operation.
-    #[inline(always)]
-    fn with_write_latch<T, E, F>(&mut self, f: F) -> Result<T, E>
-    where
-        F: FnMut(&mut SPI) -> Result<T, E>,
-        E: core::convert::From<Error<SpiError, PinError>>,
-    {
-        self.with_chip_enable(|spi| spi.write(&[instruction::WREN]).map_err(Error::Spi))?;
-        let result = self.with_chip_enable(f);
-        // write latch automatically resets on successful write
-        if result.is_err() {
-            self.with_chip_enable(|spi| spi.write(&[instruction::WRDI]).map_err(Error::Spi))?;
+    /// Write a run of bytes within a page and wait for the write cycle to
+    /// complete, returning the number of write cycles.
+    fn write_run<D: DelayUs<u32>>(
+        &mut self,
+        address: u8,
+        data: &[u8],
+        delay: &mut D,
+    ) -> Result<usize, Error<SpiError, PinError>> {
+        if data.is_empty() {
+            Ok(0)
+        } else {
+            self.page_write(address, data)?;
+            self.wait_ready(delay)?;
+            Ok(1)
         }
-        result
     }
 
-    /// Read from the EEPROM.
+    /// Erase the memory array by writing `0xFF` to every page.
     ///
-    /// # Arguments
+    /// Pages that are write protected by the block protection bits in the
+    /// STATUS register are skipped, this leaves the factory programmed
+    /// EUI-48 intact when the upper quarter is protected.
+    /// With the [`protect_eui`] guard enabled the erase stops before the
+    /// identifier.
     ///
-    /// * `address` - A byte address from 0x00 to 0xFF.
-    /// * `buf` - Buffer to read data into.
-    ///   The size of the buffer determines the number of bytes read.
+    /// Returns the number of pages written.
     ///
     /// # Example
     ///
     /// ```
     /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock as hal;
-    /// # let spi = hal::spi::Mock::new(&[
-    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
-    /// #   hal::spi::Transaction::transfer(vec![0x00; 64], vec![0x00; 64]),
-    /// # ]);
-    /// # let pin = hal::pin::Mock::new(&[
-    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
-    /// #    hal::pin::Transaction::set(hal::pin::State::High),
-    /// # ]);
+    /// # let mut spi_transactions = vec![
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x04]),
+    /// # ];
+    /// # let mut pin_transactions = vec![
+    /// #   hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #   hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ];
+    /// # for address in (0x00..0xC0).step_by(16) {
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![instruction::WREN]));
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![instruction::WRITE, address]));
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![0xFF; 16]));
+    /// #   spi_transactions.push(hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x04]));
+    /// #   for _ in 0..3 {
+    /// #     pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #     pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// #   }
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&spi_transactions);
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
     /// use eeprom25aa02e48::Eeprom25aa02e48;
     ///
-    /// let mut some_big_buf: [u8; 1024] = [0; 1024];
     /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
-    /// // read 64 bytes starting at EEPROM address 0x00
-    /// eeprom.read(0x00, &mut some_big_buf[..64])?;
+    /// // upper quarter (0xC0 to 0xFF) is write protected
+    /// let pages: usize = eeprom.erase(&mut delay)?;
+    /// assert_eq!(pages, 12);
+    /// # eeprom.free().0.done();
     /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
     /// ```
     ///
-    /// # Safety
+    /// [`protect_eui`]: Self::protect_eui
+    pub fn erase<D: DelayUs<u32>>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<usize, Error<SpiError, PinError>> {
+        let end: usize = match self.read_status_register()?.block_protect() {
+            BlockProtect::None => CAPACITY,
+            BlockProtect::Upper64 => CAPACITY / 4 * 3,
+            BlockProtect::Upper128 => CAPACITY / 2,
+            BlockProtect::All => 0,
+        }
+        .min(self.protected_start());
+
+        let blank: [u8; PAGE] = [0xFF; PAGE];
+        self.write_paged(0x00, end, delay, |_, len| &blank[..len])
+    }
+
+    /// Write `value` to every address in a range.
     ///
-    /// If the buffer length plus address exceeds the maximum address of `0xFF`
-    /// the address counter will roll over to `0x00`.
+    /// Like [`write_data`] the range is split into page writes on page
+    /// boundaries, waiting for each write cycle to complete.
     ///
-    /// # Panics
+    /// # Example
     ///
-    /// The length of the buf may not exceed 256.
+    /// Zero the addresses 0x0C to 0x13, this is split into page writes of 4
+    /// and 4 bytes.
     ///
-    /// ```should_panic
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock as hal;
-    /// # let spi = hal::spi::Mock::new(&[]);
-    /// # let pin = hal::pin::Mock::new(&[]);
+    /// # let mut spi_transactions = Vec::new();
+    /// # let mut pin_transactions = Vec::new();
+    /// # for address in [0x0C, 0x10] {
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![instruction::WREN]));
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![instruction::WRITE, address]));
+    /// #   spi_transactions.push(hal::spi::Transaction::write(vec![0x00; 4]));
+    /// #   spi_transactions.push(hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]));
+    /// #   for _ in 0..3 {
+    /// #     pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #     pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// #   }
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&spi_transactions);
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
     /// use eeprom25aa02e48::Eeprom25aa02e48;
     ///
-    /// let mut some_big_buf: [u8; 1024] = [0; 1024];
     /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
-    /// eeprom.read(0x0, &mut some_big_buf)?;
+    /// eeprom.fill(0x00, 0x0C..=0x13, &mut delay)?;
+    /// # eeprom.free().0.done();
     /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
     /// ```
-    pub fn read(&mut self, address: u8, buf: &mut [u8]) -> Result<(), Error<SpiError, PinError>> {
-        if buf.is_empty() {
-            Ok(())
-        } else {
-            // buffer is too large
-            assert!(buf.len() <= 256);
-            let cmd: [u8; 2] = [instruction::READ, address];
-            self.with_chip_enable(|spi| {
-                spi.write(&cmd).map_err(Error::Spi)?;
-                spi.transfer(buf).map_err(Error::Spi)?;
-                Ok(())
-            })
-        }
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the range exceeds the capacity.
+    ///
+    /// Returns [`Error::ProtectedRegion`] if the write overlaps the factory
+    /// programmed identifier, see [`protect_eui`].
+    ///
+    /// [`write_data`]: Self::write_data
+    /// [`protect_eui`]: Self::protect_eui
+    pub fn fill<D: DelayUs<u32>>(
+        &mut self,
+        value: u8,
+        range: RangeInclusive<u8>,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>> {
+        let (start, end) = range.into_inner();
+        check_args(usize::from(end) < CAPACITY, Error::OutOfBounds)?;
+
+        let len: usize = (usize::from(end) + 1).saturating_sub(start.into());
+        self.check_protected(start, len)?;
+        let buf: [u8; PAGE] = [value; PAGE];
+        self.write_paged(start, len, delay, |_, len| &buf[..len])?;
+        Ok(())
     }
 
-    /// Writes up to a page of data to the EEPROM.
-    ///
-    /// # Arguments
+    /// Copy `len` bytes from `src` to `dst`.
     ///
-    /// * `address` - A byte address from 0x00 to 0xFF.
-    /// * `data` - Data to write, must be less than or equal to the page size in length.
+    /// The entire source range is read before writing, overlapping ranges
+    /// are copied as if through an intermediate buffer.
     ///
     /// # Example
     ///
-    /// Write to the second page (page 1).
+    /// Copy 4 bytes from 0x00 to the overlapping range starting at 0x02.
     ///
     /// ```
     /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock as hal;
     /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 4], vec![0x01, 0x02, 0x03, 0x04]),
     /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
-    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
-    /// #   hal::spi::Transaction::write(vec![0x12; 16]),
-    /// # ]);
-    /// # let pin = hal::pin::Mock::new(&[
-    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
-    /// #    hal::pin::Transaction::set(hal::pin::State::High),
-    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
-    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x02]),
+    /// #   hal::spi::Transaction::write(vec![0x01, 0x02, 0x03, 0x04]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
     /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..4 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
     /// use eeprom25aa02e48::Eeprom25aa02e48;
     ///
-    /// let data: [u8; 16] = [0x12; 16];
     /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
-    /// eeprom.write_page(0x10, &data)?;
+    /// eeprom.copy_within(0x00, 0x02, 4, &mut delay)?;
+    /// # eeprom.free().0.done();
     /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
     /// ```
     ///
-    /// # Panics
-    ///
-    /// The data length must be less than or equal to the page size (16).
+    /// Copy 4 bytes from 0x00 to 0x1E, the write is split at the page
+    /// boundary at 0x20.
     ///
-    /// ```should_panic
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock as hal;
-    /// # let spi = hal::spi::Mock::new(&[]);
-    /// # let pin = hal::pin::Mock::new(&[]);
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::transfer(vec![0x00; 4], vec![0x01, 0x02, 0x03, 0x04]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x1E]),
+    /// #   hal::spi::Transaction::write(vec![0x01, 0x02]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x20]),
+    /// #   hal::spi::Transaction::write(vec![0x03, 0x04]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+    /// # ]);
+    /// # let mut pin_transactions = Vec::new();
+    /// # for _ in 0..7 {
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+    /// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+    /// # }
+    /// # let pin = hal::pin::Mock::new(&pin_transactions);
+    /// # let mut delay = hal::delay::MockNoop::new();
     /// use eeprom25aa02e48::Eeprom25aa02e48;
     ///
-    /// let data: [u8; 17] = [0x00; 17];
     /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
-    /// eeprom.write_page(0, &data)?;
+    /// eeprom.copy_within(0x00, 0x1E, 4, &mut delay)?;
+    /// # eeprom.free().0.done();
     /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
     /// ```
     ///
-    /// The address must be page aligned.
+    /// # Errors
     ///
-    /// ```should_panic
+    /// Returns [`Error::OutOfBounds`] if the source or destination range
+    /// exceeds the capacity.
+    ///
+    /// ```
     /// # use embedded_hal_mock as hal;
     /// # let spi = hal::spi::Mock::new(&[]);
     /// # let pin = hal::pin::Mock::new(&[]);
-    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    /// # let mut delay = hal::delay::MockNoop::new();
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
     ///
-    /// let data: [u8; 16] = [0x00; 16];
     /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
-    /// eeprom.write_page(1, &data)?;
-    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// # #[cfg(feature = "checked-errors")]
+    /// # {
+    /// let result = eeprom.copy_within(0x00, 0xF8, 16, &mut delay);
+    /// assert_eq!(result, Err(Error::OutOfBounds));
+    /// # }
     /// ```
-    pub fn write_page(
+    pub fn copy_within<D: DelayUs<u32>>(
         &mut self,
-        address: u8,
-        data: &[u8],
+        src: u8,
+        dst: u8,
+        len: usize,
+        delay: &mut D,
     ) -> Result<(), Error<SpiError, PinError>> {
-        assert!(address % PAGE_SIZE == 0);
-        if data.is_empty() {
-            Ok(())
-        } else {
-            assert!(data.len() <= PAGE_SIZE as usize);
-            let cmd: [u8; 2] = [instruction::WRITE, address];
-            self.with_write_latch(|spi| {
-                spi.write(&cmd).map_err(Error::Spi)?;
-                spi.write(data).map_err(Error::Spi)?;
-                Ok(())
-            })
-        }
+        check_args(
+            src as usize + len <= CAPACITY && dst as usize + len <= CAPACITY,
+            Error::OutOfBounds,
+        )?;
+
+        let mut buf: [u8; CAPACITY] = [0; CAPACITY];
+        let buf: &mut [u8] = &mut buf[..len];
+        self.read(src, buf)?;
+        self.write_data(dst, buf, delay)
     }
+}
 
+impl<SPI, CS, SpiError, PinError, WP, HOLD> Eeprom25aa02e48<SPI, CS, WP, HOLD>
+where
+    SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+{
     /// Read the EUI-48 MAC address from the EEPROM.
     ///
     /// # Example
@@ -326,9 +4365,162 @@ where
     /// # assert_eq!(eui48, [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
     /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
     /// ```
+    #[doc(alias = "read_eui48_array")]
     pub fn read_eui48(&mut self) -> Result<[u8; EUI48_BYTES], Error<SpiError, PinError>> {
         let mut eui48: [u8; EUI48_BYTES] = [0; EUI48_BYTES];
         self.read(EUI48_MEMORY_ADDRESS, &mut eui48)?;
         Ok(eui48)
     }
+
+    /// Read the EUI-48 MAC address from the EEPROM as an [`Eui48`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::transfer(vec![0; 6], vec![0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Eui48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let eui48: Eui48 = eeprom.read_eui48_typed()?;
+    /// # assert_eq!(eui48.to_string(), "00:04:A3:12:34:56");
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn read_eui48_typed(&mut self) -> Result<Eui48, Error<SpiError, PinError>> {
+        self.read_eui48().map(Eui48::from)
+    }
+
+    /// Read the EUI-48 MAC address from the EEPROM, and whether the EUI-48 is
+    /// write protected by the block protection bits in the STATUS register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::transfer(vec![0; 6], vec![0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]),
+    /// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x04]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let (eui48, protected): ([u8; 6], bool) = eeprom.read_eui48_with_status()?;
+    /// # assert_eq!(eui48, [0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// assert!(protected);
+    /// # eeprom.free().0.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn read_eui48_with_status(
+        &mut self,
+    ) -> Result<([u8; EUI48_BYTES], bool), Error<SpiError, PinError>> {
+        let eui48: [u8; EUI48_BYTES] = self.read_eui48()?;
+        let protected: bool = self
+            .read_status_register()?
+            .block_protect()
+            .protected_range()
+            .is_some_and(|range| range.contains(&EUI48_MEMORY_ADDRESS));
+        Ok((eui48, protected))
+    }
+
+    /// Check that the EEPROM is connected by reading the EUI-48 and
+    /// validating the OUI is [`MICROCHIP_OUI`].
+    ///
+    /// A disconnected MISO line typically reads as all `0x00` or all `0xFF`,
+    /// which fails this check.
+    ///
+    /// Microchip has been assigned other OUIs, check the EUI-48 of your parts
+    /// before relying on this in production.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::transfer(vec![0; 6], vec![0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]),
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::transfer(vec![0; 6], vec![0xFF; 6]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+    /// let eui48 = eeprom.probe()?;
+    /// # assert_eq!(eui48.to_string(), "00:04:A3:12:34:56");
+    /// // disconnected
+    /// assert_eq!(eeprom.probe(), Err(Error::InvalidOui));
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOui`] if the OUI is not [`MICROCHIP_OUI`].
+    pub fn probe(&mut self) -> Result<Eui48, Error<SpiError, PinError>> {
+        let eui48: Eui48 = self.read_eui48_typed()?;
+        if validate_eui48(eui48.as_bytes()) {
+            Ok(eui48)
+        } else {
+            Err(Error::InvalidOui)
+        }
+    }
+}
+
+impl<SPI, CS, SpiError, PinError, WP, HOLD> Eeprom25aa02e64<SPI, CS, WP, HOLD>
+where
+    SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+{
+    /// Read the EUI-64 address from the EEPROM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI64_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write(vec![instruction::READ, EUI64_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::transfer(vec![0; 8], vec![0x00, 0x04, 0xA3, 0xFF, 0xFE, 0x12, 0x34, 0x56]),
+    /// # ]);
+    /// # let pin = hal::pin::Mock::new(&[
+    /// #    hal::pin::Transaction::set(hal::pin::State::Low),
+    /// #    hal::pin::Transaction::set(hal::pin::State::High),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e64;
+    ///
+    /// let mut eeprom = Eeprom25aa02e64::new(spi, pin);
+    /// let eui64: [u8; 8] = eeprom.read_eui64()?;
+    /// # assert_eq!(eui64, [0x00, 0x04, 0xA3, 0xFF, 0xFE, 0x12, 0x34, 0x56]);
+    /// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+    /// ```
+    pub fn read_eui64(&mut self) -> Result<[u8; EUI64_BYTES], Error<SpiError, PinError>> {
+        let mut eui64: [u8; EUI64_BYTES] = [0; EUI64_BYTES];
+        self.read(EUI64_MEMORY_ADDRESS, &mut eui64)?;
+        Ok(eui64)
+    }
+
+    /// Read the EUI-64 address from the EEPROM as an [`Eui64`].
+    pub fn read_eui64_typed(&mut self) -> Result<Eui64, Error<SpiError, PinError>> {
+        self.read_eui64().map(Eui64::from)
+    }
 }
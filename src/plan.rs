@@ -0,0 +1,77 @@
+//! Page write planning.
+
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+/// Page write of a [`WritePlan`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PageWrite {
+    /// Address of the first byte written.
+    pub address: u8,
+    /// Range of the data written, as offsets into the data of the plan.
+    pub data: Range<usize>,
+}
+
+/// Iterator over the page writes needed to write `len` bytes starting from
+/// an address, with `PAGE` byte pages.
+///
+/// This is pure arithmetic, nothing is written and the plan does not check
+/// the address against the capacity of the EEPROM.
+/// [`write_data`] performs the writes of this plan, one write cycle per page
+/// write.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::{PageWrite, WritePlan};
+///
+/// let mut plan = WritePlan::<16>::new(0x0C, 24);
+/// assert_eq!(plan.next(), Some(PageWrite { address: 0x0C, data: 0..4 }));
+/// assert_eq!(plan.next(), Some(PageWrite { address: 0x10, data: 4..20 }));
+/// assert_eq!(plan.next(), Some(PageWrite { address: 0x20, data: 20..24 }));
+/// assert_eq!(plan.next(), None);
+///
+/// assert_eq!(WritePlan::<16>::new(0x00, 64).count(), 4);
+/// ```
+///
+/// [`write_data`]: crate::Eeprom25x::write_data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WritePlan<const PAGE: usize> {
+    /// Address of the first write.
+    start: usize,
+    /// Offset of the next write.
+    offset: usize,
+    /// Number of bytes to write.
+    len: usize,
+}
+
+impl<const PAGE: usize> WritePlan<PAGE> {
+    /// Creates a plan to write `len` bytes starting from `address`.
+    pub const fn new(address: u8, len: usize) -> Self {
+        WritePlan {
+            start: address as usize,
+            offset: 0,
+            len,
+        }
+    }
+}
+
+impl<const PAGE: usize> Iterator for WritePlan<PAGE> {
+    type Item = PageWrite;
+
+    fn next(&mut self) -> Option<PageWrite> {
+        if self.offset >= self.len {
+            return None;
+        }
+        let address: usize = self.start + self.offset;
+        let page_len: usize = (PAGE - address % PAGE).min(self.len - self.offset);
+        let data: Range<usize> = self.offset..self.offset + page_len;
+        self.offset += page_len;
+        Some(PageWrite {
+            address: address as u8,
+            data,
+        })
+    }
+}
+
+impl<const PAGE: usize> FusedIterator for WritePlan<PAGE> {}
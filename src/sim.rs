@@ -0,0 +1,357 @@
+//! Simulated EEPROM for host-side testing.
+//!
+//! [`SimEeprom`] emulates the 25AA02E48 command set in memory, [`SimSpi`]
+//! and [`SimCs`] connect the driver to the simulator with the
+//! [`embedded-hal`] 0.2 SPI and GPIO traits.
+//!
+//! This module is only available with the `sim` feature.
+//!
+//! # Example
+//!
+//! ```
+//! # let mut delay = embedded_hal_mock::delay::MockNoop::new();
+//! use core::cell::RefCell;
+//! use eeprom25aa02e48::{
+//!     sim::{SimCs, SimEeprom, SimSpi},
+//!     Eeprom25aa02e48, Eui48,
+//! };
+//!
+//! let eui48 = Eui48::from([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+//! let sim = RefCell::new(SimEeprom::new(eui48));
+//! let mut eeprom = Eeprom25aa02e48::new(SimSpi::new(&sim), SimCs::new(&sim));
+//!
+//! assert_eq!(eeprom.read_eui48_typed()?, eui48);
+//!
+//! eeprom.write_data(0x0C, &[0x5A; 8], &mut delay)?;
+//! let mut buf: [u8; 8] = [0; 8];
+//! eeprom.read(0x0C, &mut buf)?;
+//! assert_eq!(buf, [0x5A; 8]);
+//! assert_eq!(sim.borrow().memory()[0x0C..0x14], [0x5A; 8]);
+//! # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+//! ```
+//!
+//! Sequential reads wrap from the maximum address to `0x00`.
+//!
+//! ```
+//! use core::cell::RefCell;
+//! use eeprom25aa02e48::{
+//!     sim::{SimCs, SimEeprom, SimSpi},
+//!     Eeprom25aa02e48,
+//! };
+//!
+//! let mut memory: [u8; 256] = [0; 256];
+//! memory[0xFF] = 0x12;
+//! memory[0x00] = 0x34;
+//! let sim = RefCell::new(SimEeprom::from_memory(memory));
+//! let mut eeprom = Eeprom25aa02e48::new(SimSpi::new(&sim), SimCs::new(&sim));
+//!
+//! let mut buf: [u8; 2] = [0; 2];
+//! eeprom.read_wrapping(0xFF, &mut buf)?;
+//! assert_eq!(buf, [0x12, 0x34]);
+//!
+//! let mut buf: [u8; 300] = [0; 300];
+//! eeprom.read_wrapping(0xFF, &mut buf)?;
+//! assert_eq!(buf[..2], [0x12, 0x34]);
+//! assert_eq!(buf[256..258], [0x12, 0x34]);
+//! # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+//! ```
+//!
+//! [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+
+use crate::{instruction, Eui48, StatusRegister, EUI48_MEMORY_ADDRESS, PAGE_SIZE};
+use core::cell::RefCell;
+use core::convert::Infallible;
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::digital::v2::OutputPin;
+
+/// Capacity of the simulated memory array in bytes.
+const CAPACITY: usize = 256;
+/// Page size of the simulated memory array in bytes.
+const PAGE: usize = PAGE_SIZE as usize;
+/// Write in progress bit.
+const WIP: u8 = 1 << 0;
+/// Write enable latch bit.
+const WEL: u8 = 1 << 1;
+/// Value shifted out when the EEPROM is not driving the bus.
+const IDLE: u8 = 0xFF;
+
+/// In-memory 25AA02E48.
+///
+/// The simulator decodes the READ, WRITE, WREN, WRDI, RDSR, and WRSR
+/// instructions:
+///
+/// * Writes require the write enable latch, which is reset at the end of
+///   every write.
+/// * Page writes wrap to the start of the page.
+/// * Writes to addresses protected by the block protection bits are ignored.
+/// * Each write cycle keeps the WIP bit set for a number of STATUS register
+///   polls, see [`set_write_cycle_polls`].
+///   Instructions other than RDSR are ignored during a write cycle.
+///
+/// # Example
+///
+/// ```
+/// # let mut delay = embedded_hal_mock::delay::MockNoop::new();
+/// use core::cell::RefCell;
+/// use eeprom25aa02e48::{
+///     sim::{SimCs, SimEeprom, SimSpi},
+///     BlockProtect, Eeprom25aa02e48, Eui48,
+/// };
+///
+/// let sim = RefCell::new(SimEeprom::new(Eui48::default()));
+/// sim.borrow_mut().set_write_cycle_polls(3);
+/// let mut eeprom = Eeprom25aa02e48::new(SimSpi::new(&sim), SimCs::new(&sim));
+///
+/// eeprom.set_block_protection(BlockProtect::Upper64)?;
+/// eeprom.wait_ready(&mut delay)?;
+/// assert_eq!(sim.borrow().status_register().block_protect(), BlockProtect::Upper64);
+///
+/// // protected writes are ignored by the EEPROM
+/// eeprom.write_byte_and_wait(0xC0, 0x00, &mut delay)?;
+/// assert_eq!(sim.borrow().memory()[0xC0], 0xFF);
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+///
+/// [`set_write_cycle_polls`]: Self::set_write_cycle_polls
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimEeprom {
+    /// Memory array.
+    memory: [u8; CAPACITY],
+    /// Block protection bits of the STATUS register.
+    block_protect: u8,
+    /// Write enable latch.
+    wel: bool,
+    /// Number of STATUS register polls before the write cycle completes.
+    busy_polls: u8,
+    /// Value of `busy_polls` at the start of each write cycle.
+    write_cycle_polls: u8,
+    /// Chip select is asserted.
+    selected: bool,
+    /// Number of bytes exchanged in the current chip select frame.
+    index: usize,
+    /// Instruction of the current chip select frame.
+    opcode: u8,
+    /// Address of the current chip select frame.
+    address: u8,
+    /// Data latched by WRITE, indexed by the offset in the page.
+    page: [Option<u8>; PAGE],
+    /// Value latched by WRSR.
+    status: Option<u8>,
+}
+
+impl SimEeprom {
+    /// Creates a blank simulator with a factory programmed EUI-48.
+    ///
+    /// All other bytes are erased (`0xFF`), and the block protection bits are
+    /// cleared.
+    pub fn new(eui48: Eui48) -> Self {
+        let mut memory: [u8; CAPACITY] = [0xFF; CAPACITY];
+        memory[usize::from(EUI48_MEMORY_ADDRESS)..].copy_from_slice(eui48.as_bytes());
+        Self::from_memory(memory)
+    }
+
+    /// Creates a simulator with the given memory contents.
+    pub const fn from_memory(memory: [u8; CAPACITY]) -> Self {
+        SimEeprom {
+            memory,
+            block_protect: 0,
+            wel: false,
+            busy_polls: 0,
+            write_cycle_polls: 1,
+            selected: false,
+            index: 0,
+            opcode: 0,
+            address: 0,
+            page: [None; PAGE],
+            status: None,
+        }
+    }
+
+    /// Memory array.
+    pub const fn memory(&self) -> &[u8; CAPACITY] {
+        &self.memory
+    }
+
+    /// Mutable memory array, for setting up tests.
+    pub fn memory_mut(&mut self) -> &mut [u8; CAPACITY] {
+        &mut self.memory
+    }
+
+    /// Current value of the STATUS register.
+    ///
+    /// Unlike a RDSR instruction this does not count as a poll of the write
+    /// cycle.
+    pub fn status_register(&self) -> StatusRegister {
+        let mut status: u8 = self.block_protect;
+        if self.wel {
+            status |= WEL;
+        }
+        if self.busy_polls != 0 {
+            status |= WIP;
+        }
+        StatusRegister::from(status)
+    }
+
+    /// Set the number of STATUS register polls with the WIP bit set after
+    /// each write, the default is 1.
+    ///
+    /// A value of 0 completes write cycles immediately.
+    pub fn set_write_cycle_polls(&mut self, polls: u8) {
+        self.write_cycle_polls = polls;
+    }
+
+    fn select(&mut self) {
+        self.selected = true;
+        self.index = 0;
+        self.page = [None; PAGE];
+        self.status = None;
+    }
+
+    fn deselect(&mut self) {
+        if !self.selected {
+            return;
+        }
+        self.selected = false;
+        if self.index == 0 || self.busy_polls != 0 {
+            return;
+        }
+
+        match self.opcode {
+            instruction::WREN => self.wel = true,
+            instruction::WRDI => self.wel = false,
+            instruction::WRITE if self.wel && self.page.iter().any(Option::is_some) => {
+                self.wel = false;
+                let base: usize = usize::from(self.address) / PAGE * PAGE;
+                let protected: bool = (0..PAGE)
+                    .filter(|&offset| self.page[offset].is_some())
+                    .any(|offset| self.is_protected(base + offset));
+                if !protected {
+                    for (offset, data) in self.page.iter().enumerate() {
+                        if let Some(data) = data {
+                            self.memory[base + offset] = *data;
+                        }
+                    }
+                    self.busy_polls = self.write_cycle_polls;
+                }
+            }
+            instruction::WRSR if self.wel => {
+                if let Some(status) = self.status {
+                    self.wel = false;
+                    self.block_protect = status & StatusRegister::WRITABLE;
+                    self.busy_polls = self.write_cycle_polls;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn is_protected(&self, address: usize) -> bool {
+        match StatusRegister::from(self.block_protect)
+            .block_protect()
+            .protected_range()
+        {
+            Some(range) => range.contains(&(address as u8)),
+            None => false,
+        }
+    }
+
+    /// Exchange a byte on the bus, returning the byte shifted out.
+    fn exchange(&mut self, byte: u8) -> u8 {
+        if !self.selected {
+            return IDLE;
+        }
+        let index: usize = self.index;
+        self.index += 1;
+
+        if index == 0 {
+            self.opcode = byte;
+            return IDLE;
+        }
+        if self.opcode == instruction::RDSR {
+            let status: u8 = u8::from(self.status_register());
+            self.busy_polls = self.busy_polls.saturating_sub(1);
+            return status;
+        }
+        if self.busy_polls != 0 {
+            return IDLE;
+        }
+
+        match (self.opcode, index) {
+            (instruction::READ | instruction::WRITE, 1) => self.address = byte,
+            (instruction::READ, _) => {
+                let data: u8 = self.memory[usize::from(self.address)];
+                self.address = self.address.wrapping_add(1);
+                return data;
+            }
+            (instruction::WRITE, _) => {
+                let offset: usize = (usize::from(self.address) + index - 2) % PAGE;
+                self.page[offset] = Some(byte);
+            }
+            (instruction::WRSR, 1) => self.status = Some(byte),
+            _ => (),
+        }
+        IDLE
+    }
+}
+
+/// SPI bus connected to a [`SimEeprom`].
+#[derive(Debug, Clone, Copy)]
+pub struct SimSpi<'a>(&'a RefCell<SimEeprom>);
+
+impl<'a> SimSpi<'a> {
+    /// Creates a SPI bus connected to `sim`.
+    pub const fn new(sim: &'a RefCell<SimEeprom>) -> Self {
+        SimSpi(sim)
+    }
+}
+
+impl Transfer<u8> for SimSpi<'_> {
+    type Error = Infallible;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Infallible> {
+        let mut sim = self.0.borrow_mut();
+        words
+            .iter_mut()
+            .for_each(|word| *word = sim.exchange(*word));
+        Ok(words)
+    }
+}
+
+impl Write<u8> for SimSpi<'_> {
+    type Error = Infallible;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Infallible> {
+        let mut sim = self.0.borrow_mut();
+        words.iter().for_each(|word| {
+            sim.exchange(*word);
+        });
+        Ok(())
+    }
+}
+
+/// Chip select pin connected to a [`SimEeprom`].
+///
+/// Chip select is active low.
+#[derive(Debug, Clone, Copy)]
+pub struct SimCs<'a>(&'a RefCell<SimEeprom>);
+
+impl<'a> SimCs<'a> {
+    /// Creates a chip select pin connected to `sim`.
+    pub const fn new(sim: &'a RefCell<SimEeprom>) -> Self {
+        SimCs(sim)
+    }
+}
+
+impl OutputPin for SimCs<'_> {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        self.0.borrow_mut().select();
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        self.0.borrow_mut().deselect();
+        Ok(())
+    }
+}
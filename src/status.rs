@@ -0,0 +1,194 @@
+//! STATUS register.
+
+use core::ops::RangeInclusive;
+
+/// Write in progress bit.
+const WIP: u8 = 1 << 0;
+/// Write enable latch bit.
+const WEL: u8 = 1 << 1;
+/// Block protection bit 0.
+const BP0: u8 = 1 << 2;
+/// Block protection bit 1.
+const BP1: u8 = 1 << 3;
+
+/// Array block write protection.
+///
+/// Values are from the "Array Protection" table in the datasheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum BlockProtect {
+    /// No write protection.
+    None,
+    /// Upper quarter (`0xC0` to `0xFF`) write protected.
+    Upper64,
+    /// Upper half (`0x80` to `0xFF`) write protected.
+    Upper128,
+    /// All blocks (`0x00` to `0xFF`) write protected.
+    All,
+}
+
+impl BlockProtect {
+    const fn from_bits(bits: u8) -> BlockProtect {
+        match bits & (BP1 | BP0) {
+            0 => BlockProtect::None,
+            BP0 => BlockProtect::Upper64,
+            BP1 => BlockProtect::Upper128,
+            _ => BlockProtect::All,
+        }
+    }
+
+    /// Range of write protected addresses, `None` if no addresses are
+    /// protected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::BlockProtect;
+    ///
+    /// assert_eq!(BlockProtect::None.protected_range(), None);
+    /// assert_eq!(BlockProtect::Upper64.protected_range(), Some(0xC0..=0xFF));
+    /// assert_eq!(BlockProtect::Upper128.protected_range(), Some(0x80..=0xFF));
+    /// assert_eq!(BlockProtect::All.protected_range(), Some(0x00..=0xFF));
+    /// ```
+    pub const fn protected_range(&self) -> Option<RangeInclusive<u8>> {
+        match self {
+            BlockProtect::None => None,
+            BlockProtect::Upper64 => Some(RangeInclusive::new(0xC0, 0xFF)),
+            BlockProtect::Upper128 => Some(RangeInclusive::new(0x80, 0xFF)),
+            BlockProtect::All => Some(RangeInclusive::new(0x00, 0xFF)),
+        }
+    }
+
+    /// Smallest block protection setting that protects `address`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::BlockProtect;
+    ///
+    /// assert_eq!(BlockProtect::covering(0xFF), BlockProtect::Upper64);
+    /// assert_eq!(BlockProtect::covering(0xC0), BlockProtect::Upper64);
+    /// assert_eq!(BlockProtect::covering(0xBF), BlockProtect::Upper128);
+    /// assert_eq!(BlockProtect::covering(0x80), BlockProtect::Upper128);
+    /// assert_eq!(BlockProtect::covering(0x7F), BlockProtect::All);
+    /// assert_eq!(BlockProtect::covering(0x00), BlockProtect::All);
+    ///
+    /// for address in 0..=u8::MAX {
+    ///     let range = BlockProtect::covering(address).protected_range().unwrap();
+    ///     assert!(range.contains(&address));
+    /// }
+    /// ```
+    pub const fn covering(address: u8) -> BlockProtect {
+        match address {
+            0xC0..=0xFF => BlockProtect::Upper64,
+            0x80..=0xBF => BlockProtect::Upper128,
+            _ => BlockProtect::All,
+        }
+    }
+
+    const fn bits(self) -> u8 {
+        match self {
+            BlockProtect::None => 0,
+            BlockProtect::Upper64 => BP0,
+            BlockProtect::Upper128 => BP1,
+            BlockProtect::All => BP1 | BP0,
+        }
+    }
+}
+
+/// STATUS register value.
+///
+/// The conversions to and from `u8` are lossless, every bit of the raw
+/// register value survives a round trip.
+/// Only the block protection bits are writable, the WIP and WEL bits are
+/// masked off when the register is written back with
+/// [`write_status_register`].
+///
+/// The 25AA02E48 and 25AA02E64 have no WPEN bit, unlike larger members of
+/// the family.
+/// The write protect pin inhibits writes to the memory array and the STATUS
+/// register on its own, see [`new_with_wp`].
+/// To lock the factory programmed identifier set [`BlockProtect::Upper64`]
+/// and drive the write protect pin low, the block protection bits cannot be
+/// changed until the pin is driven high.
+///
+/// [`write_status_register`]: crate::Eeprom25x::write_status_register
+/// [`new_with_wp`]: crate::Eeprom25x::new_with_wp
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::{BlockProtect, StatusRegister};
+///
+/// let status = StatusRegister::from(0x03);
+/// assert!(status.write_in_progress());
+/// assert!(status.write_enable_latch());
+/// assert_eq!(status.block_protect(), BlockProtect::None);
+///
+/// let status = status.with_block_protect(BlockProtect::Upper128);
+/// assert_eq!(u8::from(status), 0x0B);
+///
+/// for raw in 0..=u8::MAX {
+///     assert_eq!(u8::from(StatusRegister::from(raw)), raw);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StatusRegister(u8);
+
+impl StatusRegister {
+    /// Mask of the bits that can be written with [`WRSR`].
+    ///
+    /// [`WRSR`]: crate::instruction::WRSR
+    pub(crate) const WRITABLE: u8 = BP1 | BP0;
+
+    /// Mask of the unimplemented bits, which always read as `0`.
+    pub(crate) const UNIMPLEMENTED: u8 = 0xF0;
+
+    /// Returns `true` if a write cycle is in progress.
+    pub const fn write_in_progress(&self) -> bool {
+        self.0 & WIP != 0
+    }
+
+    /// Returns `true` if the write enable latch is set.
+    pub const fn write_enable_latch(&self) -> bool {
+        self.0 & WEL != 0
+    }
+
+    /// Array block write protection.
+    pub const fn block_protect(&self) -> BlockProtect {
+        BlockProtect::from_bits(self.0)
+    }
+
+    /// Set the array block write protection.
+    #[must_use = "with_block_protect returns a modified StatusRegister"]
+    pub const fn with_block_protect(self, bp: BlockProtect) -> StatusRegister {
+        StatusRegister((self.0 & !(BP1 | BP0)) | bp.bits())
+    }
+}
+
+impl From<u8> for StatusRegister {
+    fn from(val: u8) -> StatusRegister {
+        StatusRegister(val)
+    }
+}
+
+impl From<StatusRegister> for u8 {
+    fn from(sr: StatusRegister) -> u8 {
+        sr.0
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDebug for StatusRegister {
+    /// Format the individual fields of the register.
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        f.debug_struct("StatusRegister")?
+            .field("write_in_progress", &self.write_in_progress())?
+            .field("write_enable_latch", &self.write_enable_latch())?
+            .field("block_protect", &self.block_protect())?
+            .finish()
+    }
+}
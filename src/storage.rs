@@ -0,0 +1,118 @@
+//! [`embedded-storage`] trait implementations.
+//!
+//! This module is only available with the `embedded-storage` feature.
+//!
+//! [`embedded-storage`]: https://github.com/rust-embedded-community/embedded-storage
+
+use crate::{Eeprom25x, Error, Eui48, Identifier, Interface, PAGE_SIZE};
+use core::convert::TryFrom;
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_storage::{ReadStorage, Storage};
+
+/// Storage adapter for the EEPROM.
+///
+/// This pairs the driver with a delay, which is used to wait for write cycles
+/// to complete inside [`Storage::write`].
+///
+/// Offsets are EEPROM byte addresses, the capacity is the EEPROM capacity.
+/// There are no alignment requirements, writes are split on page boundaries
+/// with [`Eeprom25x::write_data`].
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x0F]),
+/// #   hal::spi::Transaction::write(vec![0x12]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+/// #   hal::spi::Transaction::write(vec![0x34]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x0F]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 2], vec![0x12, 0x34]),
+/// # ]);
+/// # let mut pin_transactions = Vec::new();
+/// # for _ in 0..7 {
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+/// # }
+/// # let pin = hal::pin::Mock::new(&pin_transactions);
+/// # let delay = hal::delay::MockNoop::new();
+/// use eeprom25aa02e48::{storage::EepromStorage, Eeprom25aa02e48};
+/// use embedded_storage::{ReadStorage, Storage};
+///
+/// let eeprom = Eeprom25aa02e48::new(spi, pin);
+/// let mut storage = EepromStorage::new(eeprom, delay);
+/// assert_eq!(storage.capacity(), 256);
+/// storage.write(0x0F, &[0x12, 0x34])?;
+/// let mut buf: [u8; 2] = [0; 2];
+/// storage.read(0x0F, &mut buf)?;
+/// # assert_eq!(buf, [0x12, 0x34]);
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+pub struct EepromStorage<
+    SPI,
+    CS,
+    D,
+    const CAPACITY: usize = 256,
+    const PAGE: usize = { PAGE_SIZE as usize },
+    EUI = Eui48,
+    WP = (),
+    HOLD = (),
+> {
+    eeprom: Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+    delay: D,
+}
+
+impl<SPI, CS, D, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    EepromStorage<SPI, CS, D, CAPACITY, PAGE, EUI, WP, HOLD>
+{
+    /// Creates a new storage adapter from a driver and a delay.
+    pub fn new(eeprom: Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>, delay: D) -> Self {
+        EepromStorage { eeprom, delay }
+    }
+
+    /// Free the driver and delay from the storage adapter.
+    pub fn free(self) -> (Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>, D) {
+        (self.eeprom, self.delay)
+    }
+}
+
+/// Convert a storage offset to an EEPROM address.
+fn address<SpiError, PinError>(offset: u32) -> Result<u8, Error<SpiError, PinError>> {
+    u8::try_from(offset).map_err(|_| Error::OutOfBounds)
+}
+
+impl<SPI, CS, D, SpiError, PinError, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    ReadStorage for EepromStorage<SPI, CS, D, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+    EUI: Identifier,
+{
+    type Error = Error<SpiError, PinError>;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.eeprom.read(address(offset)?, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        CAPACITY
+    }
+}
+
+impl<SPI, CS, D, SpiError, PinError, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD>
+    Storage for EepromStorage<SPI, CS, D, CAPACITY, PAGE, EUI, WP, HOLD>
+where
+    SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+    EUI: Identifier,
+    D: DelayUs<u32>,
+{
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.eeprom
+            .write_data(address(offset)?, bytes, &mut self.delay)
+    }
+}
@@ -0,0 +1,705 @@
+//! Wear leveled counter and record log, and endurance tracking.
+//!
+//! This module is only available with the `wear-leveling` feature.
+
+use crate::{crc, Eeprom25x, Error, Identifier, Interface, WriteInterface, PAGE_COUNT, PAGE_SIZE};
+use core::ops::RangeInclusive;
+use embedded_hal::blocking::delay::DelayUs;
+
+/// Size of a counter slot in bytes, a sequence number followed by the value.
+const SLOT_SIZE: u8 = 8;
+
+/// Sequence number of an erased slot.
+const EMPTY: u32 = u32::MAX;
+
+/// `u32` counter spread across a range of EEPROM pages.
+///
+/// Each increment writes the counter to the next slot in the range with an
+/// incremented sequence number, the slot with the highest sequence number is
+/// the current value.
+/// This divides the wear on each cell by the number of slots, there are two
+/// slots per 16 byte page.
+///
+/// `PAGE` is the page size of the EEPROM in bytes, the default is the 16
+/// byte page of the 25AA02E48 and 25AA02E64.
+///
+/// The pages must be erased (`0xFF`) before first use.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 8], vec![1, 0, 0, 0, 5, 0, 0, 0]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x08]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 8], vec![0xFF; 8]),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x08]),
+/// #   hal::spi::Transaction::write(vec![2, 0, 0, 0, 6, 0, 0, 0]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// # ]);
+/// # let mut pin_transactions = Vec::new();
+/// # for _ in 0..5 {
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+/// # }
+/// # let pin = hal::pin::Mock::new(&pin_transactions);
+/// # let mut delay = hal::delay::MockNoop::new();
+/// use eeprom25aa02e48::{wear::WearLeveledCounter, Eeprom25aa02e48};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+/// // counter in page 0
+/// let mut counter = WearLeveledCounter::new(0..=0);
+/// assert_eq!(counter.load(&mut eeprom)?, 5);
+/// assert_eq!(counter.increment(&mut eeprom, &mut delay)?, 6);
+/// assert_eq!(counter.value(), 6);
+/// # eeprom.free().0.done();
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WearLeveledCounter<const PAGE: usize = { PAGE_SIZE as usize }> {
+    /// Address of the first slot.
+    start: u16,
+    /// Number of slots.
+    slots: u16,
+    /// Index of the slot holding the current value, `None` if all slots are
+    /// empty.
+    slot: Option<u16>,
+    /// Sequence number of the current value.
+    seq: u32,
+    /// Current value.
+    value: u32,
+}
+
+impl<const PAGE: usize> WearLeveledCounter<PAGE> {
+    /// Creates a new counter stored in a range of page indices.
+    ///
+    /// For example `0..=3` is the 4 pages from `0x00` to `0x3F`.
+    ///
+    /// The value is `0` until [`load`](Self::load) is called.
+    pub const fn new(pages: RangeInclusive<u8>) -> Self {
+        let first: u8 = *pages.start();
+        let last: u8 = *pages.end();
+        let pages: u16 = if last < first {
+            0
+        } else {
+            (last - first) as u16 + 1
+        };
+        WearLeveledCounter {
+            start: first as u16 * PAGE as u16,
+            slots: pages * (PAGE / SLOT_SIZE as usize) as u16,
+            slot: None,
+            seq: 0,
+            value: 0,
+        }
+    }
+
+    /// Current value of the counter.
+    pub const fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Address of a slot.
+    fn address(&self, slot: u16) -> u8 {
+        (usize::from(self.start) + usize::from(slot) * usize::from(SLOT_SIZE)) as u8
+    }
+
+    /// Returns `true` if all slots are within the capacity.
+    fn in_bounds(&self, capacity: usize) -> bool {
+        usize::from(self.start) + usize::from(self.slots) * usize::from(SLOT_SIZE) <= capacity
+    }
+
+    /// Load the counter from the EEPROM, returning the value.
+    ///
+    /// The value is `0` if all slots are empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the pages exceed the EEPROM capacity.
+    pub fn load<SPI, CS, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &mut self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+    ) -> Result<u32, Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+    {
+        self.slot = None;
+        self.seq = 0;
+        self.value = 0;
+
+        if !self.in_bounds(CAPACITY) {
+            return Err(Error::OutOfBounds);
+        }
+
+        for slot in 0..self.slots {
+            let buf: [u8; SLOT_SIZE as usize] = eeprom.read_array(self.address(slot))?;
+            let seq: u32 = u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]);
+            if seq != EMPTY && (self.slot.is_none() || seq > self.seq) {
+                self.slot = Some(slot);
+                self.seq = seq;
+                self.value = u32::from_le_bytes([buf[4], buf[5], buf[6], buf[7]]);
+            }
+        }
+        Ok(self.value)
+    }
+
+    /// Increment the counter, writing it to the next slot and waiting for the
+    /// write cycle to complete.
+    ///
+    /// Returns the incremented value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if there are no slots, or if the pages
+    /// exceed the EEPROM capacity.
+    pub fn increment<SPI, CS, D, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &mut self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+        delay: &mut D,
+    ) -> Result<u32, Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+        D: DelayUs<u32>,
+    {
+        if self.slots == 0 || !self.in_bounds(CAPACITY) {
+            return Err(Error::OutOfBounds);
+        }
+
+        let (slot, seq) = match self.slot {
+            Some(slot) => ((slot + 1) % self.slots, self.seq.wrapping_add(1)),
+            None => (0, 0),
+        };
+        let value: u32 = self.value.wrapping_add(1);
+
+        let mut buf: [u8; SLOT_SIZE as usize] = [0; SLOT_SIZE as usize];
+        buf[..4].copy_from_slice(&seq.to_le_bytes());
+        buf[4..].copy_from_slice(&value.to_le_bytes());
+        eeprom.write_data(self.address(slot), &buf, delay)?;
+
+        self.slot = Some(slot);
+        self.seq = seq;
+        self.value = value;
+        Ok(value)
+    }
+}
+
+/// Size of a log header in bytes, a sequence number followed by a CRC-8.
+const HEADER_SIZE: usize = 5;
+
+/// Record read from a [`RingLog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Record<const LEN: usize> {
+    /// Sequence number of the record.
+    pub seq: u32,
+    /// Record data.
+    pub data: [u8; LEN],
+}
+
+/// Log of `LEN` byte records spread across a range of EEPROM pages.
+///
+/// Each append writes the record to the next slot in the range with an
+/// incremented sequence number, overwriting the oldest record once every slot
+/// is in use.
+/// This divides the wear on each cell by the number of slots.
+///
+/// Each slot holds the sequence number, a CRC-8 of the sequence number and
+/// record, and the record.
+/// Slots are `LEN + 5` bytes and are packed back to back, slots may cross
+/// page boundaries.
+/// The record is written before the header, a record interrupted by a power
+/// loss fails the CRC-8 check and is skipped.
+///
+/// The pages must be erased (`0xFF`) before first use.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 5], vec![1, 0, 0, 0, 0x0B]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x05]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 3], vec![0x11, 0x22, 0x33]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x08]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 5], vec![0xFF; 5]),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x0D]),
+/// #   hal::spi::Transaction::write(vec![0x44, 0x55, 0x66]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x08]),
+/// #   hal::spi::Transaction::write(vec![2, 0, 0, 0, 0xEC]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x08]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 5], vec![2, 0, 0, 0, 0xEC]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x0D]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 3], vec![0x44, 0x55, 0x66]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 5], vec![1, 0, 0, 0, 0x0B]),
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x05]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 3], vec![0x11, 0x22, 0x33]),
+/// # ]);
+/// # let mut pin_transactions = Vec::new();
+/// # for _ in 0..13 {
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+/// # }
+/// # let pin = hal::pin::Mock::new(&pin_transactions);
+/// # let mut delay = hal::delay::MockNoop::new();
+/// use eeprom25aa02e48::{
+///     wear::{Record, RingLog},
+///     Eeprom25aa02e48,
+/// };
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+/// // 2 slots of 3 byte records in page 0
+/// let mut log: RingLog<3> = RingLog::new(0..=0);
+/// assert_eq!(log.slots(), 2);
+/// assert_eq!(log.load(&mut eeprom)?, Some(1));
+/// assert_eq!(log.append(&mut eeprom, &[0x44, 0x55, 0x66], &mut delay)?, 2);
+///
+/// let mut records = log.iter(&mut eeprom);
+/// assert_eq!(records.next(), Some(Ok(Record { seq: 2, data: [0x44, 0x55, 0x66] })));
+/// assert_eq!(records.next(), Some(Ok(Record { seq: 1, data: [0x11, 0x22, 0x33] })));
+/// assert_eq!(records.next(), None);
+/// # eeprom.free().0.done();
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingLog<const LEN: usize, const PAGE: usize = { PAGE_SIZE as usize }> {
+    /// Address of the first slot.
+    start: u16,
+    /// Number of slots.
+    slots: u16,
+    /// Index of the slot holding the newest record, `None` if all slots are
+    /// empty.
+    slot: Option<u16>,
+    /// Sequence number of the newest record.
+    seq: u32,
+}
+
+impl<const LEN: usize, const PAGE: usize> RingLog<LEN, PAGE> {
+    /// Size of a slot in bytes.
+    const SLOT_SIZE: usize = HEADER_SIZE + LEN;
+
+    /// Creates a new log stored in a range of page indices.
+    ///
+    /// For example `0..=3` is the 4 pages from `0x00` to `0x3F`.
+    ///
+    /// The log is empty until [`load`](Self::load) is called.
+    pub const fn new(pages: RangeInclusive<u8>) -> Self {
+        let first: u8 = *pages.start();
+        let last: u8 = *pages.end();
+        let bytes: usize = if last < first {
+            0
+        } else {
+            (last - first) as usize * PAGE + PAGE
+        };
+        RingLog {
+            start: first as u16 * PAGE as u16,
+            slots: (bytes / Self::SLOT_SIZE) as u16,
+            slot: None,
+            seq: 0,
+        }
+    }
+
+    /// Number of slots, the maximum number of records.
+    pub const fn slots(&self) -> usize {
+        self.slots as usize
+    }
+
+    /// Address of a slot.
+    fn address(&self, slot: u16) -> u8 {
+        (usize::from(self.start) + usize::from(slot) * Self::SLOT_SIZE) as u8
+    }
+
+    /// Returns `true` if all slots are within the capacity.
+    fn in_bounds(&self, capacity: usize) -> bool {
+        usize::from(self.start) + usize::from(self.slots) * Self::SLOT_SIZE <= capacity
+    }
+
+    /// CRC-8 of the sequence number and record.
+    fn crc(seq: u32, record: &[u8]) -> u8 {
+        crc::update8(crc::crc8(&seq.to_le_bytes()), record)
+    }
+
+    /// Read a slot, returning `None` if the slot is empty or fails the CRC-8
+    /// check.
+    fn read_slot<SPI, CS, const CAPACITY: usize, EUI, WP, HOLD>(
+        &self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+        slot: u16,
+    ) -> Result<Option<Record<LEN>>, Error<SPI::SpiError, SPI::PinError>>
+    where
+        SPI: Interface<CS>,
+        EUI: Identifier,
+    {
+        let address: u8 = self.address(slot);
+        let header: [u8; HEADER_SIZE] = eeprom.read_array(address)?;
+        let seq: u32 = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        if seq == EMPTY {
+            return Ok(None);
+        }
+        let record: [u8; LEN] = eeprom.read_array(address + HEADER_SIZE as u8)?;
+        if Self::crc(seq, &record) == header[4] {
+            Ok(Some(Record { seq, data: record }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Load the log from the EEPROM, returning the sequence number of the
+    /// newest record.
+    ///
+    /// Returns `None` if the log is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the pages exceed the EEPROM capacity.
+    pub fn load<SPI, CS, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &mut self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+    ) -> Result<Option<u32>, Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+    {
+        self.slot = None;
+        self.seq = 0;
+
+        if !self.in_bounds(CAPACITY) {
+            return Err(Error::OutOfBounds);
+        }
+
+        for slot in 0..self.slots {
+            if let Some(Record { seq, .. }) = self.read_slot(eeprom, slot)? {
+                if self.slot.is_none() || seq > self.seq {
+                    self.slot = Some(slot);
+                    self.seq = seq;
+                }
+            }
+        }
+        Ok(self.slot.map(|_| self.seq))
+    }
+
+    /// Append a record, writing it to the next slot and waiting for the write
+    /// cycles to complete.
+    ///
+    /// Returns the sequence number of the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if there are no slots, or if the pages
+    /// exceed the EEPROM capacity.
+    pub fn append<SPI, CS, D, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &mut self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+        record: &[u8; LEN],
+        delay: &mut D,
+    ) -> Result<u32, Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+        D: DelayUs<u32>,
+    {
+        if self.slots == 0 || !self.in_bounds(CAPACITY) {
+            return Err(Error::OutOfBounds);
+        }
+
+        let (slot, seq) = match self.slot {
+            Some(slot) => ((slot + 1) % self.slots, self.seq.wrapping_add(1)),
+            None => (0, 0),
+        };
+
+        let mut header: [u8; HEADER_SIZE] = [0; HEADER_SIZE];
+        header[..4].copy_from_slice(&seq.to_le_bytes());
+        header[4] = Self::crc(seq, record);
+
+        let address: u8 = self.address(slot);
+        eeprom.write_data(address + HEADER_SIZE as u8, record, delay)?;
+        eeprom.write_data(address, &header, delay)?;
+
+        self.slot = Some(slot);
+        self.seq = seq;
+        Ok(seq)
+    }
+
+    /// Read the newest record.
+    ///
+    /// Returns `None` if the log is empty.
+    pub fn read_latest<SPI, CS, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+    ) -> Result<Option<Record<LEN>>, Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+    {
+        self.iter(eeprom).next().transpose()
+    }
+
+    /// Iterate over the records, newest first.
+    ///
+    /// Iteration stops at the first record that is missing or out of
+    /// sequence, and after the first error.
+    pub fn iter<'a, SPI, CS, const CAPACITY: usize, EUI, WP, HOLD>(
+        &'a self,
+        eeprom: &'a mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+    ) -> Records<'a, SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD, LEN> {
+        Records {
+            remaining: if self.in_bounds(CAPACITY) {
+                self.slot.map_or(0, |_| self.slots)
+            } else {
+                0
+            },
+            slot: self.slot.unwrap_or(0),
+            seq: self.seq,
+            log: self,
+            eeprom,
+        }
+    }
+}
+
+/// Iterator over the records of a [`RingLog`], newest first.
+///
+/// Created by [`RingLog::iter`].
+pub struct Records<
+    'a,
+    SPI,
+    CS,
+    const CAPACITY: usize,
+    const PAGE: usize,
+    EUI,
+    WP,
+    HOLD,
+    const LEN: usize,
+> {
+    /// Log being read.
+    log: &'a RingLog<LEN, PAGE>,
+    /// EEPROM holding the log.
+    eeprom: &'a mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+    /// Number of slots left to read.
+    remaining: u16,
+    /// Next slot to read.
+    slot: u16,
+    /// Expected sequence number of the next slot.
+    seq: u32,
+}
+
+impl<SPI, CS, const CAPACITY: usize, const PAGE: usize, EUI, WP, HOLD, const LEN: usize> Iterator
+    for Records<'_, SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD, LEN>
+where
+    SPI: Interface<CS>,
+    EUI: Identifier,
+{
+    type Item = Result<
+        Record<LEN>,
+        Error<<SPI as WriteInterface<CS>>::SpiError, <SPI as WriteInterface<CS>>::PinError>,
+    >;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        match self.log.read_slot(self.eeprom, self.slot) {
+            Ok(Some(record)) if record.seq == self.seq => {
+                self.slot = self.slot.checked_sub(1).unwrap_or(self.log.slots - 1);
+                self.seq = record.seq.wrapping_sub(1);
+                Some(Ok(record))
+            }
+            Ok(_) => {
+                self.remaining = 0;
+                None
+            }
+            Err(e) => {
+                self.remaining = 0;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Write endurance of each page from the datasheet, in write cycles.
+pub const ENDURANCE: u32 = 1_000_000;
+
+/// Number of write cycles per persisted count.
+const RESOLUTION: u32 = 64;
+
+/// Size of a persisted count in bytes.
+const COUNT_SIZE: usize = 2;
+
+/// Approximate number of write cycles of each page.
+///
+/// Writes made through the tracker are counted in memory, and the count of a
+/// page is persisted to the EEPROM every 64 write cycles of that page.
+/// The counts are only approximate, up to 63 write cycles per page are lost
+/// on each reset.
+///
+/// The counts use 32 bytes, two 16 byte pages, starting from the page given
+/// to [`new`](Self::new), a count is written once for every 64 write cycles
+/// of the tracked page, so the tracker pages wear out slower than the pages
+/// they track.
+/// Writes to the tracker pages themselves are not counted.
+///
+/// The pages must be erased (`0xFF`) before first use.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock as hal;
+/// # let mut counts = vec![0xFF; 32];
+/// # counts[2] = 0x02;
+/// # counts[3] = 0x00;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0xD0]),
+/// #   hal::spi::Transaction::transfer(vec![0x00; 32], counts),
+/// #   hal::spi::Transaction::write(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::write(vec![instruction::WRITE, 0x10]),
+/// #   hal::spi::Transaction::write(vec![0x12; 16]),
+/// #   hal::spi::Transaction::transfer(vec![instruction::RDSR, 0x00], vec![0x00, 0x00]),
+/// # ]);
+/// # let mut pin_transactions = Vec::new();
+/// # for _ in 0..4 {
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::Low));
+/// #   pin_transactions.push(hal::pin::Transaction::set(hal::pin::State::High));
+/// # }
+/// # let pin = hal::pin::Mock::new(&pin_transactions);
+/// # let mut delay = hal::delay::MockNoop::new();
+/// use eeprom25aa02e48::{
+///     wear::{EnduranceTracker, ENDURANCE},
+///     Eeprom25aa02e48,
+/// };
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi, pin);
+/// // counts in pages 13 and 14
+/// let mut tracker = EnduranceTracker::new(13);
+/// tracker.load(&mut eeprom)?;
+/// assert_eq!(tracker.writes(1), 128);
+///
+/// tracker.write_data(&mut eeprom, 0x10, &[0x12; 16], &mut delay)?;
+/// assert_eq!(tracker.writes(1), 129);
+/// assert_eq!(tracker.writes_remaining(1), ENDURANCE - 129);
+/// # eeprom.free().0.done();
+/// # Ok::<(), eeprom25aa02e48::Error<_, _>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnduranceTracker<const PAGE: usize = { PAGE_SIZE as usize }> {
+    /// Address of the persisted counts.
+    start: u16,
+    /// Write cycles of each page.
+    writes: [u32; PAGE_COUNT],
+}
+
+impl<const PAGE: usize> EnduranceTracker<PAGE> {
+    /// Creates a new tracker with the counts stored in two pages starting from
+    /// a page index.
+    ///
+    /// For example `13` is the 2 pages from `0xD0` to `0xEF`.
+    ///
+    /// The counts are `0` until [`load`](Self::load) is called.
+    pub const fn new(page: u8) -> Self {
+        EnduranceTracker {
+            start: page as u16 * PAGE as u16,
+            writes: [0; PAGE_COUNT],
+        }
+    }
+
+    /// Approximate number of write cycles of a page.
+    ///
+    /// Returns `0` for pages that are not tracked.
+    pub fn writes(&self, page: usize) -> u32 {
+        self.writes.get(page).copied().unwrap_or(0)
+    }
+
+    /// Estimated number of write cycles left before the page reaches the
+    /// datasheet [`ENDURANCE`].
+    pub fn writes_remaining(&self, page: usize) -> u32 {
+        ENDURANCE.saturating_sub(self.writes(page))
+    }
+
+    /// Returns `true` if the counts are within the capacity, and every page
+    /// has a count.
+    fn in_bounds(&self, capacity: usize) -> bool {
+        usize::from(self.start) + PAGE_COUNT * COUNT_SIZE <= capacity
+            && capacity.div_ceil(PAGE) <= PAGE_COUNT
+    }
+
+    /// Load the counts from the EEPROM.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the counts exceed the EEPROM
+    /// capacity, or if the EEPROM has more than [`PAGE_COUNT`] pages.
+    pub fn load<SPI, CS, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &mut self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+    ) -> Result<(), Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+    {
+        if !self.in_bounds(CAPACITY) {
+            return Err(Error::OutOfBounds);
+        }
+
+        let counts: [u8; PAGE_COUNT * COUNT_SIZE] = eeprom.read_array(self.start as u8)?;
+        for (writes, count) in self.writes.iter_mut().zip(counts.chunks_exact(COUNT_SIZE)) {
+            *writes = match u16::from_le_bytes([count[0], count[1]]) {
+                u16::MAX => 0,
+                count => u32::from(count) * RESOLUTION,
+            };
+        }
+        Ok(())
+    }
+
+    /// Write data of any length to the EEPROM with
+    /// [`write_data`](Eeprom25x::write_data), and count the write cycle of
+    /// each page written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the counts exceed the EEPROM
+    /// capacity, or if the EEPROM has more than [`PAGE_COUNT`] pages, and
+    /// any error from [`write_data`](Eeprom25x::write_data).
+    pub fn write_data<SPI, CS, D, SpiError, PinError, const CAPACITY: usize, EUI, WP, HOLD>(
+        &mut self,
+        eeprom: &mut Eeprom25x<SPI, CS, CAPACITY, PAGE, EUI, WP, HOLD>,
+        address: u8,
+        data: &[u8],
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError, PinError>>
+    where
+        SPI: Interface<CS, SpiError = SpiError, PinError = PinError>,
+        EUI: Identifier,
+        D: DelayUs<u32>,
+    {
+        if !self.in_bounds(CAPACITY) {
+            return Err(Error::OutOfBounds);
+        }
+        eeprom.write_data(address, data, delay)?;
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let first: usize = usize::from(address) / PAGE;
+        let last: usize = (usize::from(address) + data.len() - 1) / PAGE;
+        for page in first..=last {
+            self.writes[page] = self.writes[page].saturating_add(1);
+            if self.writes[page].is_multiple_of(RESOLUTION) {
+                let count: u16 =
+                    (self.writes[page] / RESOLUTION).min(u32::from(u16::MAX - 1)) as u16;
+                let address: u8 = (usize::from(self.start) + page * COUNT_SIZE) as u8;
+                eeprom.write_data(address, &count.to_le_bytes(), delay)?;
+            }
+        }
+        Ok(())
+    }
+}